@@ -1,4 +1,4 @@
-use crate::{parse::LazyGen, program, repl};
+use crate::{helper::Rng, parse::LazyGen, program, repl};
 use clap::{ArgEnum, IntoApp, Parser, Subcommand};
 use std::{
     collections::VecDeque,
@@ -121,6 +121,18 @@ pub struct Eolina {
     #[clap(short, long)]
     quiet: bool,
 
+    ///
+    /// The maximum size in bytes of values created by repeating
+    ///
+    #[clap(short, long, value_name = "BYTES", default_value_t = program::DEFAULT_MAX_SIZE)]
+    max_size: usize,
+
+    ///
+    /// The seed for random tokens, a random seed is used if not given
+    ///
+    #[clap(short, long)]
+    seed: Option<u64>,
+
     ///
     /// A program or path to a file containing a program
     ///
@@ -215,10 +227,10 @@ impl Eolina {
             .apply()?;
 
         match self.subcommand {
-            Some(SubCommand::Repl) => cmd_repl(),
+            Some(SubCommand::Repl) => cmd_repl(self.max_size, self.seed),
             None => {
                 if let Some(program) = self.program {
-                    cmd_eval(program, self.inputs)
+                    cmd_eval(program, self.inputs, self.max_size, self.seed)
                 } else {
                     let mut app = Self::into_app();
                     app.print_help()?;
@@ -245,8 +257,14 @@ impl Eolina {
 ///   * the program was neither a path nor a valid program
 ///   * the program context failed
 ///
-fn cmd_eval(program: String, mut inputs: Vec<String>) -> color_eyre::Result<ExitCode> {
+fn cmd_eval(
+    program: String,
+    mut inputs: Vec<String>,
+    max_size: usize,
+    seed: Option<u64>,
+) -> color_eyre::Result<ExitCode> {
     let mut queue = VecDeque::new();
+    let rng = seed.map_or_else(Rng::from_time, Rng::new);
     let mut file_contents = String::new();
 
     let input = {
@@ -281,6 +299,8 @@ fn cmd_eval(program: String, mut inputs: Vec<String>) -> color_eyre::Result<Exit
         Some(inputs),
         &mut queue,
         false,
+        max_size,
+        rng,
     );
 
     // execute it
@@ -304,12 +324,12 @@ fn cmd_eval(program: String, mut inputs: Vec<String>) -> color_eyre::Result<Exit
 ///   * the program was neither a path nor a valid program
 ///   * the repl context failed
 ///
-fn cmd_repl() -> color_eyre::Result<ExitCode> {
+fn cmd_repl(max_size: usize, seed: Option<u64>) -> color_eyre::Result<ExitCode> {
     if !*super::IS_IN_TTY || !*super::IS_OUT_TTY || !*super::IS_ERR_TTY {
         color_eyre::eyre::bail!("cannot start repl in a non-tty env".to_owned());
     }
 
-    let mut context = repl::Context::new();
+    let mut context = repl::Context::new(max_size, seed.map_or_else(Rng::from_time, Rng::new));
     context.run()?;
 
     Ok(ExitCode::Ok)
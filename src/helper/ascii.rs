@@ -18,6 +18,17 @@ pub trait AsciiExt {
     ///
     fn into_swap(self) -> Self;
 
+    ///
+    /// Shifts the ascii letters of [`self`] by `shift` places in the alphabet, wrapping around in
+    /// both directions and preserving their case.
+    ///
+    fn into_rotated(self, shift: isize) -> Self;
+
+    ///
+    /// Mirrors the ascii letters of [`self`] in the alphabet, preserving their case.
+    ///
+    fn into_atbash(self) -> Self;
+
     ///
     /// Returns whether [`self`] is in it's uppercase represenation.
     ///
@@ -58,6 +69,25 @@ impl AsciiExt for char {
         }
     }
 
+    fn into_rotated(self, shift: isize) -> Self {
+        let base = match self {
+            'a'..='z' => b'a',
+            'A'..='Z' => b'A',
+            _ => return self,
+        };
+
+        let offset = (self as u8 - base) as isize + shift.rem_euclid(26);
+        (base + offset.rem_euclid(26) as u8) as char
+    }
+
+    fn into_atbash(self) -> Self {
+        match self {
+            'a'..='z' => (b'z' - (self as u8 - b'a')) as char,
+            'A'..='Z' => (b'Z' - (self as u8 - b'A')) as char,
+            _ => self,
+        }
+    }
+
     fn is_lower(&self) -> bool {
         self.is_ascii_lowercase()
     }
@@ -103,6 +133,14 @@ impl AsciiExt for String {
         self.chars().map(|ch| ch.into_swap()).collect()
     }
 
+    fn into_rotated(self, shift: isize) -> Self {
+        self.chars().map(|ch| ch.into_rotated(shift)).collect()
+    }
+
+    fn into_atbash(self) -> Self {
+        self.chars().map(|ch| ch.into_atbash()).collect()
+    }
+
     fn is_upper(&self) -> bool {
         self.chars().all(|ch| ch.is_upper())
     }
@@ -145,6 +183,22 @@ mod test {
         assert_eq!('6'.into_swap(), '6');
     }
 
+    #[test]
+    fn char_into_rotated() {
+        assert_eq!('a'.into_rotated(13), 'n');
+        assert_eq!('Y'.into_rotated(3), 'B');
+        assert_eq!('b'.into_rotated(-3), 'y');
+        assert_eq!('c'.into_rotated(-55), 'z');
+        assert_eq!('6'.into_rotated(1), '6');
+    }
+
+    #[test]
+    fn char_into_atbash() {
+        assert_eq!('a'.into_atbash(), 'z');
+        assert_eq!('N'.into_atbash(), 'M');
+        assert_eq!('6'.into_atbash(), '6');
+    }
+
     #[test]
     fn char_is_lower() {
         assert!(!'A'.to_owned().is_lower());
@@ -194,6 +248,22 @@ mod test {
         assert_eq!("6!".to_owned().into_swap(), "6!");
     }
 
+    #[test]
+    fn string_into_rotated() {
+        assert_eq!("Hello, World!".to_owned().into_rotated(13), "Uryyb, Jbeyq!");
+        assert_eq!("Uryyb, Jbeyq!".to_owned().into_rotated(13), "Hello, World!");
+        assert_eq!("abc xyz".to_owned().into_rotated(-1), "zab wxy");
+        assert_eq!("äb".to_owned().into_rotated(26), "äb");
+        assert_eq!("az".to_owned().into_rotated(isize::MAX), "hg");
+        assert_eq!("az".to_owned().into_rotated(isize::MIN), "sr");
+    }
+
+    #[test]
+    fn string_into_atbash() {
+        assert_eq!("Wizard 9".to_owned().into_atbash(), "Draziw 9");
+        assert_eq!("Draziw 9".to_owned().into_atbash(), "Wizard 9");
+    }
+
     #[test]
     fn string_is_lower() {
         assert!("abc".to_owned().is_lower());
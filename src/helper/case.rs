@@ -0,0 +1,241 @@
+///
+/// An extension trait for converting strings between title and identifier cases.
+///
+pub trait CaseExt {
+    ///
+    /// Converts [`self`] to title case, every whitespace separated word starts uppercase and
+    /// continues lowercase.
+    ///
+    fn into_title(self) -> Self;
+
+    ///
+    /// Converts the first [`char`] of [`self`] to uppercase, leaving the rest as is.
+    ///
+    fn into_capitalized(self) -> Self;
+
+    ///
+    /// Converts [`self`] to `snake_case`.
+    ///
+    fn into_snake(self) -> Self;
+
+    ///
+    /// Converts [`self`] to `SCREAMING_CASE`.
+    ///
+    fn into_screaming(self) -> Self;
+
+    ///
+    /// Converts [`self`] to `kebab-case`.
+    ///
+    fn into_kebab(self) -> Self;
+
+    ///
+    /// Converts [`self`] to `camelCase`.
+    ///
+    fn into_camel(self) -> Self;
+
+    ///
+    /// Converts [`self`] to `PascalCase`.
+    ///
+    fn into_pascal(self) -> Self;
+}
+
+impl CaseExt for String {
+    fn into_title(self) -> Self {
+        let mut ret = String::with_capacity(self.len());
+        let mut is_start = true;
+
+        for ch in self.chars() {
+            if ch.is_whitespace() {
+                is_start = true;
+                ret.push(ch);
+            } else if is_start {
+                is_start = false;
+                ret.extend(ch.to_uppercase());
+            } else {
+                ret.extend(ch.to_lowercase());
+            }
+        }
+
+        ret
+    }
+
+    fn into_capitalized(self) -> Self {
+        let mut chars = self.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => self,
+        }
+    }
+
+    fn into_snake(self) -> Self {
+        words(&self)
+            .into_iter()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn into_screaming(self) -> Self {
+        words(&self)
+            .into_iter()
+            .map(str::to_uppercase)
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn into_kebab(self) -> Self {
+        words(&self)
+            .into_iter()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    fn into_camel(self) -> Self {
+        let mut words = words(&self).into_iter();
+        match words.next() {
+            Some(first) => first
+                .to_lowercase()
+                .chars()
+                .chain(words.flat_map(capitalize_word))
+                .collect(),
+            None => String::new(),
+        }
+    }
+
+    fn into_pascal(self) -> Self {
+        words(&self).into_iter().flat_map(capitalize_word).collect()
+    }
+}
+
+///
+/// Uppercases the first [`char`] of `word` and lowercases the rest.
+///
+fn capitalize_word(word: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .into_iter()
+        .flat_map(char::to_uppercase)
+        .chain(chars.flat_map(char::to_lowercase))
+}
+
+///
+/// Splits `input` into its words. Words are separated by non alphanumeric [`char`]s, a lowercase
+/// followed by an uppercase [`char`], the last uppercase [`char`] before a lowercase one and
+/// transitions between digits and letters.
+///
+/// ### Returns
+///
+/// The words in order of occurence, without any separators.
+///
+fn words(input: &str) -> Vec<&str> {
+    let chars = input.char_indices().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut start = None;
+
+    for (at, &(idx, ch)) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if let Some(start) = start.take() {
+                words.push(&input[start..idx]);
+            }
+            continue;
+        }
+
+        match start {
+            Some(word_start) => {
+                // a word was started, so the previous char is alphanumeric
+                let prev = chars[at - 1].1;
+                let next = chars.get(at + 1).map(|&(_, ch)| ch);
+
+                let is_boundary = (prev.is_lowercase() && ch.is_uppercase())
+                    || (prev.is_numeric() != ch.is_numeric())
+                    || (prev.is_uppercase()
+                        && ch.is_uppercase()
+                        && next.map_or(false, char::is_lowercase));
+
+                if is_boundary {
+                    words.push(&input[word_start..idx]);
+                    start = Some(idx);
+                }
+            }
+            None => start = Some(idx),
+        }
+    }
+
+    if let Some(start) = start {
+        words.push(&input[start..]);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn words() {
+        assert_eq!(super::words("hello world"), ["hello", "world"]);
+        assert_eq!(
+            super::words("  fooBar_baz-qux "),
+            ["foo", "Bar", "baz", "qux"]
+        );
+        assert_eq!(super::words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(super::words("v2beta10"), ["v", "2", "beta", "10"]);
+        assert!(super::words(" -_ ").is_empty());
+    }
+
+    #[test]
+    fn string_into_title() {
+        assert_eq!("hello wORLD".to_owned().into_title(), "Hello World");
+        assert_eq!(" a  b-c".to_owned().into_title(), " A  B-c");
+        assert_eq!("".to_owned().into_title(), "");
+    }
+
+    #[test]
+    fn string_into_capitalized() {
+        assert_eq!("hello wORLD".to_owned().into_capitalized(), "Hello wORLD");
+        assert_eq!("6!".to_owned().into_capitalized(), "6!");
+        assert_eq!("".to_owned().into_capitalized(), "");
+    }
+
+    #[test]
+    fn string_into_snake() {
+        assert_eq!("Hello World".to_owned().into_snake(), "hello_world");
+        assert_eq!(
+            "parseHTTPResponse2".to_owned().into_snake(),
+            "parse_http_response_2"
+        );
+        assert_eq!("kebab-case".to_owned().into_snake(), "kebab_case");
+    }
+
+    #[test]
+    fn string_into_screaming() {
+        assert_eq!("Hello World".to_owned().into_screaming(), "HELLO_WORLD");
+        assert_eq!("maxValue".to_owned().into_screaming(), "MAX_VALUE");
+    }
+
+    #[test]
+    fn string_into_kebab() {
+        assert_eq!("Hello World".to_owned().into_kebab(), "hello-world");
+        assert_eq!("snake_case".to_owned().into_kebab(), "snake-case");
+    }
+
+    #[test]
+    fn string_into_camel() {
+        assert_eq!("Hello World".to_owned().into_camel(), "helloWorld");
+        assert_eq!("SCREAMING_CASE".to_owned().into_camel(), "screamingCase");
+        assert_eq!("user id 2".to_owned().into_camel(), "userId2");
+        assert_eq!("".to_owned().into_camel(), "");
+    }
+
+    #[test]
+    fn string_into_pascal() {
+        assert_eq!("Hello World".to_owned().into_pascal(), "HelloWorld");
+        assert_eq!(
+            "xml_http_request".to_owned().into_pascal(),
+            "XmlHttpRequest"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::fmt::{self, Display, Formatter};
+
+///
+/// An error which occurs when decoding an input with an [`Encoding`].
+///
+#[derive(thiserror::Error, Debug)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub enum DecodeError {
+    ///
+    /// The input contained an invalid byte or ended unexpectedly. Contains the encoding and the
+    /// offending byte offset in the input.
+    ///
+    #[error("invalid {0} input at byte {1}")]
+    InvalidInput(Encoding, usize),
+
+    ///
+    /// The decoded bytes were not valid utf-8. Contains the encoding and the offending byte
+    /// offset in the decoded bytes.
+    ///
+    #[error("decoded {0} input is not valid utf-8 at byte {1}")]
+    InvalidUtf8(Encoding, usize),
+}
+
+///
+/// A binary to text encoding.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Encoding {
+    ///
+    /// The standard base64 encoding with padding.
+    ///
+    Base64,
+
+    ///
+    /// The url and file name safe base64 encoding without padding.
+    ///
+    Base64Url,
+
+    ///
+    /// The lowercase hexadecimal encoding.
+    ///
+    Hex,
+
+    ///
+    /// The url percent encoding, all but unreserved bytes are encoded.
+    ///
+    Percent,
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+impl Encoding {
+    ///
+    /// Encodes the utf-8 bytes of the given `input`.
+    ///
+    pub fn encode(&self, input: &str) -> String {
+        match self {
+            Self::Base64 => encode_base64(input.as_bytes(), BASE64, true),
+            Self::Base64Url => encode_base64(input.as_bytes(), BASE64_URL, false),
+            Self::Hex => input
+                .bytes()
+                .flat_map(|byte| [HEX[byte as usize >> 4], HEX[byte as usize & 0xf]])
+                .map(char::from)
+                .collect(),
+            Self::Percent => {
+                let mut ret = String::with_capacity(input.len());
+                for byte in input.bytes() {
+                    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                        ret.push(byte as char);
+                    } else {
+                        ret.push('%');
+                        ret.push(HEX[byte as usize >> 4].to_ascii_uppercase() as char);
+                        ret.push(HEX[byte as usize & 0xf].to_ascii_uppercase() as char);
+                    }
+                }
+                ret
+            }
+        }
+    }
+
+    ///
+    /// Decodes the given `input` into a utf-8 [`String`].
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the input was successfully decoded, contains the decoded [`String`]
+    /// * [`Err`]
+    ///   * the input was not valid for this encoding
+    ///   * the decoded bytes were not valid utf-8
+    ///
+    pub fn decode(&self, input: &str) -> Result<String, DecodeError> {
+        let bytes = match self {
+            // padding is optional for both variants
+            Self::Base64 => decode_base64(input.as_bytes(), BASE64, *self)?,
+            Self::Base64Url => decode_base64(input.as_bytes(), BASE64_URL, *self)?,
+            Self::Hex => {
+                let input = input.as_bytes();
+                if input.len() % 2 != 0 {
+                    return Err(DecodeError::InvalidInput(*self, input.len() - 1));
+                }
+
+                let mut ret = Vec::with_capacity(input.len() / 2);
+                for (idx, pair) in input.chunks(2).enumerate() {
+                    let high =
+                        hex_value(pair[0]).ok_or(DecodeError::InvalidInput(*self, idx * 2))?;
+                    let low =
+                        hex_value(pair[1]).ok_or(DecodeError::InvalidInput(*self, idx * 2 + 1))?;
+                    ret.push(high << 4 | low);
+                }
+                ret
+            }
+            Self::Percent => {
+                let input = input.as_bytes();
+                let mut ret = Vec::with_capacity(input.len());
+
+                let mut idx = 0;
+                while idx < input.len() {
+                    if input[idx] == b'%' {
+                        let high = input.get(idx + 1).copied().and_then(hex_value);
+                        let low = input.get(idx + 2).copied().and_then(hex_value);
+                        match (high, low) {
+                            (Some(high), Some(low)) => ret.push(high << 4 | low),
+                            _ => return Err(DecodeError::InvalidInput(*self, idx)),
+                        }
+                        idx += 3;
+                    } else {
+                        ret.push(input[idx]);
+                        idx += 1;
+                    }
+                }
+                ret
+            }
+        };
+
+        String::from_utf8(bytes)
+            .map_err(|err| DecodeError::InvalidUtf8(*self, err.utf8_error().valid_up_to()))
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64 => f.write_str("b64"),
+            Self::Base64Url => f.write_str("b64u"),
+            Self::Hex => f.write_str("hex"),
+            Self::Percent => f.write_str("url"),
+        }
+    }
+}
+
+///
+/// Returns the value of the given ascii hex digit in either case.
+///
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
+
+fn encode_base64(input: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut ret = String::with_capacity((input.len() + 2) / 3 * 4);
+
+    for chunk in input.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (idx, &byte)| {
+            acc | (byte as u32) << (16 - idx * 8)
+        });
+
+        // n bytes are encoded by n + 1 chars
+        for idx in 0..=chunk.len() {
+            ret.push(alphabet[(group >> (18 - idx * 6)) as usize & 0x3f] as char);
+        }
+
+        if pad {
+            for _ in chunk.len()..3 {
+                ret.push('=');
+            }
+        }
+    }
+
+    ret
+}
+
+fn decode_base64(
+    input: &[u8],
+    alphabet: &[u8; 64],
+    encoding: Encoding,
+) -> Result<Vec<u8>, DecodeError> {
+    let data_len = input
+        .iter()
+        .rposition(|&byte| byte != b'=')
+        .map_or(0, |idx| idx + 1);
+
+    // padding may only fill up the last group
+    if input.len() - data_len > 2 || (data_len != input.len() && input.len() % 4 != 0) {
+        return Err(DecodeError::InvalidInput(encoding, data_len));
+    }
+
+    // a single char in the last group cannot encode a full byte
+    if data_len % 4 == 1 {
+        return Err(DecodeError::InvalidInput(encoding, data_len - 1));
+    }
+
+    let mut ret = Vec::with_capacity(data_len / 4 * 3 + 2);
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    for (idx, &byte) in input[..data_len].iter().enumerate() {
+        let value = alphabet
+            .iter()
+            .position(|&ch| ch == byte)
+            .ok_or(DecodeError::InvalidInput(encoding, idx))?;
+
+        acc = acc << 6 | value as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            ret.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        assert_eq!(Encoding::Base64.encode(""), "");
+        assert_eq!(Encoding::Base64.encode("f"), "Zg==");
+        assert_eq!(Encoding::Base64.encode("fo"), "Zm8=");
+        assert_eq!(Encoding::Base64.encode("foo"), "Zm9v");
+        assert_eq!(Encoding::Base64.encode("foobar"), "Zm9vYmFy");
+        assert_eq!(Encoding::Base64.encode("?>?"), "Pz4/");
+
+        assert_eq!(Encoding::Base64.decode("Zg==").unwrap(), "f");
+        assert_eq!(Encoding::Base64.decode("Zm8").unwrap(), "fo");
+        assert_eq!(Encoding::Base64.decode("Zm9vYmFy").unwrap(), "foobar");
+        assert_eq!(
+            Encoding::Base64.decode("Zm9v!mFy").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Base64, 4)
+        );
+        assert_eq!(
+            Encoding::Base64.decode("Zm9vY").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Base64, 4)
+        );
+        assert_eq!(
+            Encoding::Base64.decode("Zg===").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Base64, 2)
+        );
+        assert_eq!(
+            Encoding::Base64.decode("gA==").unwrap_err(),
+            DecodeError::InvalidUtf8(Encoding::Base64, 0)
+        );
+    }
+
+    #[test]
+    fn base64_url() {
+        assert_eq!(Encoding::Base64Url.encode("?>?"), "Pz4_");
+        assert_eq!(Encoding::Base64Url.encode("fo"), "Zm8");
+        assert_eq!(Encoding::Base64Url.decode("Pz4_").unwrap(), "?>?");
+        assert_eq!(Encoding::Base64Url.decode("Zm8=").unwrap(), "fo");
+        assert_eq!(
+            Encoding::Base64Url.decode("Pz4/").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Base64Url, 3)
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(Encoding::Hex.encode("Az\n"), "417a0a");
+        assert_eq!(Encoding::Hex.decode("417A0a").unwrap(), "Az\n");
+        assert_eq!(
+            Encoding::Hex.decode("417").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Hex, 2)
+        );
+        assert_eq!(
+            Encoding::Hex.decode("41xa").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Hex, 2)
+        );
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(Encoding::Percent.encode("a b/c~ä"), "a%20b%2Fc~%C3%A4");
+        assert_eq!(
+            Encoding::Percent.decode("a%20b%2fc~%C3%A4").unwrap(),
+            "a b/c~ä"
+        );
+        assert_eq!(Encoding::Percent.decode("a+b").unwrap(), "a+b");
+        assert_eq!(
+            Encoding::Percent.decode("ab%2").unwrap_err(),
+            DecodeError::InvalidInput(Encoding::Percent, 2)
+        );
+        assert_eq!(
+            Encoding::Percent.decode("%C3").unwrap_err(),
+            DecodeError::InvalidUtf8(Encoding::Percent, 0)
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use std::fmt::{self, Display, Formatter};
+
+///
+/// An error which occurs when unescaping an input of a [`Format`].
+///
+#[derive(thiserror::Error, Debug)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub enum UnescapeError {
+    ///
+    /// The input contained an invalid escape sequence, quote or ended unexpectedly. Contains the
+    /// format and the offending byte offset in the input.
+    ///
+    #[error("invalid {0} input at byte {1}")]
+    InvalidInput(Format, usize),
+}
+
+///
+/// A text format which requires escaping to embed arbitrary strings.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    ///
+    /// A quoted json string literal.
+    ///
+    Json,
+
+    ///
+    /// A single quoted posix shell word.
+    ///
+    Shell,
+
+    ///
+    /// Html text or attribute values.
+    ///
+    Html,
+
+    ///
+    /// A csv field, quoted only if required.
+    ///
+    Csv,
+
+    ///
+    /// A regex matching the input literally.
+    ///
+    Regex,
+}
+
+impl Format {
+    ///
+    /// Escapes the given `input` for this format.
+    ///
+    pub fn escape(&self, input: &str) -> String {
+        match self {
+            Self::Json => {
+                let mut ret = String::with_capacity(input.len() + 2);
+                ret.push('"');
+                for ch in input.chars() {
+                    match ch {
+                        '"' => ret.push_str("\\\""),
+                        '\\' => ret.push_str("\\\\"),
+                        '\n' => ret.push_str("\\n"),
+                        '\r' => ret.push_str("\\r"),
+                        '\t' => ret.push_str("\\t"),
+                        '\u{8}' => ret.push_str("\\b"),
+                        '\u{c}' => ret.push_str("\\f"),
+                        ch if ch < ' ' => ret.push_str(&format!("\\u{:04x}", ch as u32)),
+                        ch => ret.push(ch),
+                    }
+                }
+                ret.push('"');
+                ret
+            }
+            Self::Shell => format!("'{}'", input.replace('\'', "'\\''")),
+            Self::Html => {
+                let mut ret = String::with_capacity(input.len());
+                for ch in input.chars() {
+                    match ch {
+                        '&' => ret.push_str("&amp;"),
+                        '<' => ret.push_str("&lt;"),
+                        '>' => ret.push_str("&gt;"),
+                        '"' => ret.push_str("&quot;"),
+                        '\'' => ret.push_str("&#39;"),
+                        ch => ret.push(ch),
+                    }
+                }
+                ret
+            }
+            Self::Csv => {
+                if input.contains(|ch| matches!(ch, ',' | '"' | '\n' | '\r')) {
+                    format!("\"{}\"", input.replace('"', "\"\""))
+                } else {
+                    input.to_owned()
+                }
+            }
+            Self::Regex => {
+                let mut ret = String::with_capacity(input.len());
+                for ch in input.chars() {
+                    if "\\.+*?()|[]{}^$".contains(ch) {
+                        ret.push('\\');
+                    }
+                    ret.push(ch);
+                }
+                ret
+            }
+        }
+    }
+
+    ///
+    /// Unescapes the given `input` of this format.
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the input was successfully unescaped, contains the unescaped [`String`]
+    /// * [`Err`]
+    ///   * the input was not valid for this format
+    ///
+    pub fn unescape(&self, input: &str) -> Result<String, UnescapeError> {
+        let err = |offset| UnescapeError::InvalidInput(*self, offset);
+
+        match self {
+            Self::Json => {
+                let inner = input
+                    .strip_prefix('"')
+                    .ok_or_else(|| err(0))?
+                    .strip_suffix('"')
+                    .ok_or_else(|| err(input.len()))?;
+
+                let mut ret = String::with_capacity(inner.len());
+                let mut chars = inner.char_indices().map(|(idx, ch)| (idx + 1, ch));
+
+                while let Some((idx, ch)) = chars.next() {
+                    match ch {
+                        '"' => return Err(err(idx)),
+                        '\\' => ret.push(match chars.next() {
+                            Some((_, '"')) => '"',
+                            Some((_, '\\')) => '\\',
+                            Some((_, '/')) => '/',
+                            Some((_, 'n')) => '\n',
+                            Some((_, 'r')) => '\r',
+                            Some((_, 't')) => '\t',
+                            Some((_, 'b')) => '\u{8}',
+                            Some((_, 'f')) => '\u{c}',
+                            Some((_, 'u')) => {
+                                let high = json_code_unit(&inner[idx..]).ok_or_else(|| err(idx))?;
+                                chars.nth(3);
+
+                                if (0xd800..0xdc00).contains(&high) {
+                                    // a high surrogate must be followed by an escaped low one
+                                    let low = inner[idx + 5..]
+                                        .strip_prefix("\\")
+                                        .and_then(json_code_unit)
+                                        .filter(|low| (0xdc00..0xe000).contains(low))
+                                        .ok_or_else(|| err(idx))?;
+                                    chars.nth(5);
+
+                                    char::from_u32(
+                                        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00),
+                                    )
+                                    .ok_or_else(|| err(idx))?
+                                } else {
+                                    char::from_u32(high).ok_or_else(|| err(idx))?
+                                }
+                            }
+                            _ => return Err(err(idx)),
+                        }),
+                        ch if ch < ' ' => return Err(err(idx)),
+                        ch => ret.push(ch),
+                    }
+                }
+
+                Ok(ret)
+            }
+            Self::Shell => {
+                let mut ret = String::with_capacity(input.len());
+                let mut chars = input.char_indices();
+
+                while let Some((idx, ch)) = chars.next() {
+                    match ch {
+                        '\'' => loop {
+                            match chars.next() {
+                                Some((_, '\'')) => break,
+                                Some((_, ch)) => ret.push(ch),
+                                None => return Err(err(idx)),
+                            }
+                        },
+                        '\\' => ret.push(chars.next().ok_or_else(|| err(idx))?.1),
+                        ch => ret.push(ch),
+                    }
+                }
+
+                Ok(ret)
+            }
+            Self::Html => {
+                let mut ret = String::with_capacity(input.len());
+                let mut rest = input;
+
+                while let Some(start) = rest.find('&') {
+                    ret.push_str(&rest[..start]);
+
+                    let offset = input.len() - rest.len() + start;
+                    let end = rest[start..].find(';').ok_or_else(|| err(offset))?;
+                    let entity = &rest[start + 1..start + end];
+
+                    ret.push(match entity {
+                        "amp" => '&',
+                        "lt" => '<',
+                        "gt" => '>',
+                        "quot" => '"',
+                        "apos" => '\'',
+                        "nbsp" => '\u{a0}',
+                        _ => entity
+                            .strip_prefix("#x")
+                            .or_else(|| entity.strip_prefix("#X"))
+                            .map(|hex| u32::from_str_radix(hex, 16))
+                            .or_else(|| entity.strip_prefix('#').map(str::parse))
+                            .and_then(Result::ok)
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| err(offset))?,
+                    });
+
+                    rest = &rest[start + end + 1..];
+                }
+
+                ret.push_str(rest);
+                Ok(ret)
+            }
+            Self::Csv => match input.strip_prefix('"') {
+                Some(quoted) => {
+                    let inner = quoted.strip_suffix('"').ok_or_else(|| err(input.len()))?;
+
+                    // every quote inside must be doubled
+                    let mut ret = String::with_capacity(inner.len());
+                    let mut chars = inner.char_indices().map(|(idx, ch)| (idx + 1, ch));
+                    while let Some((idx, ch)) = chars.next() {
+                        if ch == '"' && chars.next().map(|(_, ch)| ch) != Some('"') {
+                            return Err(err(idx));
+                        }
+                        ret.push(ch);
+                    }
+
+                    Ok(ret)
+                }
+                None => Ok(input.to_owned()),
+            },
+            Self::Regex => {
+                let mut ret = String::with_capacity(input.len());
+                let mut chars = input.char_indices();
+
+                while let Some((idx, ch)) = chars.next() {
+                    if ch == '\\' {
+                        ret.push(chars.next().ok_or_else(|| err(idx))?.1);
+                    } else {
+                        ret.push(ch);
+                    }
+                }
+
+                Ok(ret)
+            }
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => f.write_str("json"),
+            Self::Shell => f.write_str("sh"),
+            Self::Html => f.write_str("html"),
+            Self::Csv => f.write_str("csv"),
+            Self::Regex => f.write_str("re"),
+        }
+    }
+}
+
+///
+/// Parses the 4 hex digits following the `u` of a json unicode escape at the start of `input`.
+///
+fn json_code_unit(input: &str) -> Option<u32> {
+    let digits = input.strip_prefix('u')?.get(..4)?;
+    if digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        u32::from_str_radix(digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            Format::Json.escape("a\"b\\c\nd\u{1}é"),
+            "\"a\\\"b\\\\c\\nd\\u0001é\""
+        );
+        assert_eq!(
+            Format::Json
+                .unescape("\"a\\\"b\\\\c\\nd\\u0001é\\/\"")
+                .unwrap(),
+            "a\"b\\c\nd\u{1}é/"
+        );
+        assert_eq!(Format::Json.unescape("\"\\ud83e\\udd80\"").unwrap(), "🦀");
+        assert_eq!(
+            Format::Json.unescape("abc").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Json, 0)
+        );
+        assert_eq!(
+            Format::Json.unescape("\"a\\xb\"").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Json, 2)
+        );
+        assert_eq!(
+            Format::Json.unescape("\"a\"b\"").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Json, 2)
+        );
+        assert_eq!(
+            Format::Json.unescape("\"\\ud83e\"").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Json, 1)
+        );
+    }
+
+    #[test]
+    fn shell() {
+        assert_eq!(Format::Shell.escape("it's $HOME"), "'it'\\''s $HOME'");
+        assert_eq!(
+            Format::Shell.unescape("'it'\\''s $HOME'").unwrap(),
+            "it's $HOME"
+        );
+        assert_eq!(Format::Shell.unescape("a\\ b'c'").unwrap(), "a bc");
+        assert_eq!(
+            Format::Shell.unescape("a'bc").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Shell, 1)
+        );
+    }
+
+    #[test]
+    fn html() {
+        assert_eq!(
+            Format::Html.escape("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+        assert_eq!(
+            Format::Html
+                .unescape("&lt;b&gt; &#65;&#x42;&amp;&quot;")
+                .unwrap(),
+            "<b> AB&\""
+        );
+        assert_eq!(
+            Format::Html.unescape("a & b").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Html, 2)
+        );
+        assert_eq!(
+            Format::Html.unescape("a&foo;").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Html, 1)
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(Format::Csv.escape("abc"), "abc");
+        assert_eq!(Format::Csv.escape("a,\"b\""), "\"a,\"\"b\"\"\"");
+        assert_eq!(Format::Csv.unescape("\"a,\"\"b\"\"\"").unwrap(), "a,\"b\"");
+        assert_eq!(Format::Csv.unescape("abc").unwrap(), "abc");
+        assert_eq!(
+            Format::Csv.unescape("\"a\"b\"").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Csv, 2)
+        );
+        assert_eq!(
+            Format::Csv.unescape("\"ab").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Csv, 3)
+        );
+    }
+
+    #[test]
+    fn regex() {
+        assert_eq!(Format::Regex.escape("a.b*(c)"), "a\\.b\\*\\(c\\)");
+        assert_eq!(
+            Format::Regex.unescape("a\\.b\\*\\(c\\)").unwrap(),
+            "a.b*(c)"
+        );
+        assert_eq!(
+            Format::Regex.unescape("ab\\").unwrap_err(),
+            UnescapeError::InvalidInput(Format::Regex, 2)
+        );
+    }
+}
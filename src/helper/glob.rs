@@ -0,0 +1,267 @@
+use std::mem;
+
+///
+/// An error which occurs when parsing a [`Glob`].
+///
+#[derive(thiserror::Error, Debug)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub enum GlobError {
+    ///
+    /// A char class was opened but not closed. Contains the byte offset of the opening bracket.
+    ///
+    #[error("unclosed char class at byte {0}")]
+    UnclosedClass(usize),
+
+    ///
+    /// The pattern ended with an escape. Contains it's byte offset.
+    ///
+    #[error("trailing escape at byte {0}")]
+    TrailingEscape(usize),
+}
+
+///
+/// A part of a [`Glob`].
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Part {
+    ///
+    /// A [`char`] matching itself.
+    ///
+    Literal(char),
+
+    ///
+    /// The wildcard `?` matching any [`char`] but `/`.
+    ///
+    Any,
+
+    ///
+    /// The wildcard `*` matching any [`char`]s but `/`.
+    ///
+    Star,
+
+    ///
+    /// The wildcard `**` matching any [`char`]s.
+    ///
+    DoubleStar,
+
+    ///
+    /// The wildcard `**/` matching nothing or any [`char`]s ending with `/`.
+    ///
+    Directories,
+
+    ///
+    /// A char class `[x]` matching any of the inclusive [`char`] ranges, or `[!x]` matching none.
+    ///
+    Class(bool, Vec<(char, char)>),
+}
+
+///
+/// A parsed glob pattern matching whole strings.
+///
+/// * `?` matches any [`char`] but `/`
+/// * `*` matches any [`char`]s but `/`
+/// * `**` matches any [`char`]s, `**/` additionally matches nothing
+/// * `[abc]`, `[a-z]` match any of the [`char`]s, `[!abc]` or `[^abc]` none of them
+/// * `\x` matches `x` literally
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Glob {
+    parts: Vec<Part>,
+}
+
+impl Glob {
+    ///
+    /// Parses the given `input` pattern.
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the pattern was valid, contains the parsed [`Glob`]
+    /// * [`Err`]
+    ///   * the pattern contained an unclosed char class or a trailing escape
+    ///
+    pub fn parse(input: &str) -> Result<Self, GlobError> {
+        let mut parts = vec![];
+        let mut chars = input.char_indices().peekable();
+
+        while let Some((idx, ch)) = chars.next() {
+            parts.push(match ch {
+                '?' => Part::Any,
+                '*' => {
+                    if chars.next_if(|&(_, ch)| ch == '*').is_none() {
+                        Part::Star
+                    } else {
+                        while chars.next_if(|&(_, ch)| ch == '*').is_some() {}
+
+                        if chars.next_if(|&(_, ch)| ch == '/').is_some() {
+                            Part::Directories
+                        } else {
+                            Part::DoubleStar
+                        }
+                    }
+                }
+                '\\' => Part::Literal(chars.next().ok_or(GlobError::TrailingEscape(idx))?.1),
+                '[' => {
+                    let negated = chars.next_if(|&(_, ch)| ch == '!' || ch == '^').is_some();
+                    let mut ranges = vec![];
+
+                    // a leading `]` is part of the class
+                    let mut first = true;
+                    loop {
+                        let (_, start) = chars.next().ok_or(GlobError::UnclosedClass(idx))?;
+                        if start == ']' && !first {
+                            break;
+                        }
+                        first = false;
+
+                        let end = match chars.peek() {
+                            Some(&(_, '-')) => {
+                                let mut lookahead = chars.clone();
+                                lookahead.next();
+                                match lookahead.next() {
+                                    Some((_, end)) if end != ']' => {
+                                        chars = lookahead;
+                                        end
+                                    }
+                                    _ => start,
+                                }
+                            }
+                            _ => start,
+                        };
+
+                        ranges.push((start, end));
+                    }
+
+                    Part::Class(negated, ranges)
+                }
+                ch => Part::Literal(ch),
+            });
+        }
+
+        Ok(Self { parts })
+    }
+
+    ///
+    /// Returns whether the whole `input` matches this [`Glob`].
+    ///
+    pub fn is_match(&self, input: &str) -> bool {
+        let chars = input.chars().collect::<Vec<_>>();
+
+        // next[j] is whether the parts after the current one match the chars from j, current[j]
+        // whether the parts from the current one do
+        let mut next = vec![false; chars.len() + 1];
+        let mut current = vec![false; chars.len() + 1];
+        next[chars.len()] = true;
+
+        for part in self.parts.iter().rev() {
+            // whether a `/` followed by a match of the next parts occurs from j onwards
+            let mut slash_match = false;
+
+            for j in (0..=chars.len()).rev() {
+                let ch = chars.get(j).copied();
+                let next_char = || next.get(j + 1).copied().unwrap_or(false);
+
+                current[j] = match part {
+                    Part::Literal(literal) => ch == Some(*literal) && next_char(),
+                    Part::Any => ch.map_or(false, |ch| ch != '/') && next_char(),
+                    Part::Star => next[j] || ch.map_or(false, |ch| ch != '/') && current[j + 1],
+                    Part::DoubleStar => next[j] || ch.is_some() && current[j + 1],
+                    Part::Directories => {
+                        slash_match |= ch == Some('/') && next_char();
+                        next[j] || slash_match
+                    }
+                    Part::Class(negated, ranges) => {
+                        ch.map_or(false, |ch| {
+                            ranges
+                                .iter()
+                                .any(|&(start, end)| (start..=end).contains(&ch))
+                                != *negated
+                        }) && next_char()
+                    }
+                };
+            }
+
+            mem::swap(&mut next, &mut current);
+        }
+
+        next[0]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_match(pattern: &str, input: &str) -> bool {
+        Glob::parse(pattern).unwrap().is_match(input)
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Glob::parse("a[!b-d]").unwrap(),
+            Glob {
+                parts: vec![Part::Literal('a'), Part::Class(true, vec![('b', 'd')])]
+            }
+        );
+        assert_eq!(
+            Glob::parse("[]-]").unwrap(),
+            Glob {
+                parts: vec![Part::Class(false, vec![(']', ']'), ('-', '-')])]
+            }
+        );
+        assert_eq!(
+            Glob::parse("***/").unwrap(),
+            Glob {
+                parts: vec![Part::Directories]
+            }
+        );
+        assert_eq!(
+            Glob::parse("a[bc").unwrap_err(),
+            GlobError::UnclosedClass(1)
+        );
+        assert_eq!(Glob::parse("[]").unwrap_err(), GlobError::UnclosedClass(0));
+        assert_eq!(
+            Glob::parse("ab\\").unwrap_err(),
+            GlobError::TrailingEscape(2)
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(is_match("*.rs", "main.rs"));
+        assert!(is_match("*.rs", ".rs"));
+        assert!(!is_match("*.rs", "src/main.rs"));
+        assert!(!is_match("*.rs", "main.rsx"));
+        assert!(is_match("a?c", "abc"));
+        assert!(is_match("a?c", "aäc"));
+        assert!(!is_match("a?c", "a/c"));
+        assert!(!is_match("a?c", "ac"));
+        assert!(is_match("", ""));
+        assert!(!is_match("", "a"));
+        assert!(is_match("*", ""));
+    }
+
+    #[test]
+    fn double_star() {
+        assert!(is_match("src/**/*.rs", "src/main.rs"));
+        assert!(is_match("src/**/*.rs", "src/helper/glob/mod.rs"));
+        assert!(!is_match("src/**/*.rs", "test/main.rs"));
+        assert!(is_match("**.rs", "a/b.rs"));
+        assert!(is_match("a**", "a/b/c"));
+        assert!(is_match("**/", "a/b/"));
+        assert!(!is_match("**/", "a/b"));
+    }
+
+    #[test]
+    fn classes() {
+        assert!(is_match("[abc]x", "bx"));
+        assert!(!is_match("[abc]x", "dx"));
+        assert!(is_match("[a-c0-9]", "5"));
+        assert!(is_match("[!a-c]", "d"));
+        assert!(!is_match("[^a-c]", "a"));
+        assert!(is_match("[a-]", "-"));
+        assert!(is_match("\\*\\?", "*?"));
+        assert!(!is_match("\\*", "a"));
+    }
+}
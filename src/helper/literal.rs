@@ -0,0 +1,229 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+};
+
+///
+/// A literal [`str`] which is matched either exactly or ignoring case. Case is ignored by
+/// comparing the unicode lowercase mappings of both sides [`char`] by [`char`], which is no full
+/// case folding, `ß` does not match `ss`. Byte offsets always refer to the original input.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Literal<'a> {
+    ///
+    /// The text to match.
+    ///
+    pub text: &'a str,
+
+    ///
+    /// Whether case is ignored when matching.
+    ///
+    pub ignore_case: bool,
+}
+
+impl<'a> Literal<'a> {
+    ///
+    /// Creates a new [`Literal`] from the given `text`.
+    ///
+    pub fn new(text: &'a str, ignore_case: bool) -> Self {
+        Self { text, ignore_case }
+    }
+
+    ///
+    /// Returns whether `input` equals this [`Literal`].
+    ///
+    pub fn is_eq(&self, input: &str) -> bool {
+        if self.ignore_case {
+            lowercase(input).eq(lowercase(self.text))
+        } else {
+            input == self.text
+        }
+    }
+
+    ///
+    /// Returns whether `input` contains this [`Literal`].
+    ///
+    pub fn is_in(&self, input: &str) -> bool {
+        self.find(input).is_some()
+    }
+
+    ///
+    /// Returns the byte offset of the first occurence of this [`Literal`] in `input`.
+    ///
+    pub fn find(&self, input: &str) -> Option<usize> {
+        if self.ignore_case {
+            boundaries(input).find(|&idx| self.match_len(&input[idx..]).is_some())
+        } else {
+            input.find(self.text)
+        }
+    }
+
+    ///
+    /// Returns the byte offset of the last occurence of this [`Literal`] in `input`.
+    ///
+    pub fn rfind(&self, input: &str) -> Option<usize> {
+        if self.ignore_case {
+            boundaries(input)
+                .rev()
+                .find(|&idx| self.match_len(&input[idx..]).is_some())
+        } else {
+            input.rfind(self.text)
+        }
+    }
+
+    ///
+    /// Returns the byte ranges of all non-overlapping occurences of this [`Literal`] in `input`
+    /// from start to end.
+    ///
+    pub fn matches(&self, input: &str) -> Vec<Range<usize>> {
+        if !self.ignore_case {
+            return input
+                .match_indices(self.text)
+                .map(|(idx, text)| idx..idx + text.len())
+                .collect();
+        }
+
+        let mut ret = vec![];
+        let mut next = 0;
+        for idx in boundaries(input) {
+            if idx < next {
+                continue;
+            }
+
+            if let Some(len) = self.match_len(&input[idx..]) {
+                ret.push(idx..idx + len);
+                next = idx + len;
+            }
+        }
+
+        ret
+    }
+
+    ///
+    /// Splits `input` at all non-overlapping occurences of this [`Literal`].
+    ///
+    pub fn split<'i>(&self, input: &'i str) -> Vec<&'i str> {
+        let mut ret = vec![];
+        let mut start = 0;
+        for range in self.matches(input) {
+            ret.push(&input[start..range.start]);
+            start = range.end;
+        }
+
+        ret.push(&input[start..]);
+        ret
+    }
+
+    ///
+    /// Returns the byte length of the case ignoring match at the start of `input`, if the match
+    /// ends on a [`char`] boundary.
+    ///
+    fn match_len(&self, input: &str) -> Option<usize> {
+        let mut needle = lowercase(self.text).peekable();
+
+        for (idx, ch) in input.char_indices() {
+            if needle.peek().is_none() {
+                return Some(idx);
+            }
+
+            for lower in ch.to_lowercase() {
+                if needle.next() != Some(lower) {
+                    return None;
+                }
+            }
+        }
+
+        needle.peek().is_none().then(|| input.len())
+    }
+}
+
+impl<'a> Display for Literal<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.ignore_case {
+            f.write_str("I")?;
+        }
+
+        write!(f, "{:?}", self.text)
+    }
+}
+
+impl<'a> From<&'a str> for Literal<'a> {
+    ///
+    /// Creates a new case sensitive [`Literal`] from the given [`str`].
+    ///
+    fn from(text: &'a str) -> Self {
+        Self::new(text, false)
+    }
+}
+
+///
+/// Returns the lowercase mapping of the given `input`, [`char`] by [`char`].
+///
+pub fn lowercase(input: &str) -> impl Iterator<Item = char> + '_ {
+    input.chars().flat_map(char::to_lowercase)
+}
+
+///
+/// Returns all [`char`] boundaries of `input` including it's end.
+///
+fn boundaries(input: &str) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    input
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain(std::iter::once(input.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_eq() {
+        assert!(Literal::new("abc", false).is_eq("abc"));
+        assert!(!Literal::new("abc", false).is_eq("ABC"));
+        assert!(Literal::new("abc", true).is_eq("AbC"));
+        assert!(Literal::new("ÄÖ", true).is_eq("äö"));
+        assert!(!Literal::new("ab", true).is_eq("abc"));
+        assert!(!Literal::new("ß", true).is_eq("SS"));
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(Literal::new("b", false).find("aBcb"), Some(3));
+        assert_eq!(Literal::new("b", true).find("aBcb"), Some(1));
+        assert_eq!(Literal::new("b", true).rfind("aBcB"), Some(3));
+        assert_eq!(Literal::new("AA", true).rfind("aaa"), Some(1));
+        assert_eq!(Literal::new("ö", true).find("xÖ"), Some(1));
+        assert_eq!(Literal::new("x", true).find("abc"), None);
+        assert_eq!(Literal::new("", true).find("abc"), Some(0));
+        assert_eq!(Literal::new("", true).rfind("abc"), Some(3));
+
+        // `İ` lowercases to `i` followed by a combining dot, a match must end on a boundary
+        assert_eq!(Literal::new("i", true).find("İ"), None);
+        assert_eq!(Literal::new("i\u{307}", true).find("aİ"), Some(1));
+        assert!(Literal::new("İ", true).is_in("xi\u{307}x"));
+    }
+
+    #[test]
+    fn matches() {
+        assert_eq!(
+            Literal::new("ab", true).matches("xAbaBab"),
+            [1..3, 3..5, 5..7]
+        );
+        assert_eq!(Literal::new("aa", true).matches("AAA"), [0..2]);
+        assert_eq!(Literal::new("aa", false).matches("aAaa"), [2..4]);
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(Literal::new("x", true).split("aXbxc"), ["a", "b", "c"]);
+        assert_eq!(Literal::new("x", false).split("aXbxc"), ["aXb", "c"]);
+        assert_eq!(Literal::new("x", true).split("X"), ["", ""]);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Literal::new("a\"b", false).to_string(), "\"a\\\"b\"");
+        assert_eq!(Literal::new("ab", true).to_string(), "I\"ab\"");
+    }
+}
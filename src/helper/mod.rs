@@ -1,6 +1,41 @@
 mod ascii;
 pub use ascii::AsciiExt;
 
+mod case;
+pub use case::CaseExt;
+
+mod encoding;
+pub use encoding::DecodeError;
+pub use encoding::Encoding;
+
+mod escape;
+pub use escape::Format;
+pub use escape::UnescapeError;
+
+mod similarity;
+pub use similarity::damerau;
+pub use similarity::jaro_winkler;
+pub use similarity::levenshtein;
+pub use similarity::unified_diff;
+
+mod template;
+pub use template::Template;
+pub use template::TemplateError;
+
+mod unicode;
+pub use unicode::UnicodeExt;
+
+mod literal;
+pub use literal::lowercase;
+pub use literal::Literal;
+
+mod random;
+pub use random::Rng;
+
+mod glob;
+pub use glob::Glob;
+pub use glob::GlobError;
+
 mod range;
 pub use range::EolinaIndex;
 pub use range::EolinaRange;
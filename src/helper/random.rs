@@ -0,0 +1,136 @@
+use std::{
+    cell::Cell,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+///
+/// A small seedable pseudo random number generator using the splitmix64 algorithm, it is not
+/// suitable for cryptographic purposes.
+///
+/// The state is kept in a [`Cell`] so a generator can be shared by reference.
+///
+#[derive(Debug)]
+pub struct Rng {
+    state: Cell<u64>,
+}
+
+impl Rng {
+    ///
+    /// Creates a new [`Rng`] with the given `seed`, equal seeds yield equal sequences.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Cell::new(seed),
+        }
+    }
+
+    ///
+    /// Creates a new [`Rng`] seeded from the system time.
+    ///
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+
+        Self::new(nanos)
+    }
+
+    ///
+    /// Resets the state of this [`Rng`] to the given `seed`.
+    ///
+    pub fn reseed(&self, seed: u64) {
+        self.state.set(seed);
+    }
+
+    ///
+    /// Returns the next random [`u64`].
+    ///
+    pub fn next_u64(&self) -> u64 {
+        let state = self.state.get().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        self.state.set(state);
+
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    ///
+    /// Returns a uniformly distributed random [`usize`] in `0..bound`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `bound` is `0`.
+    ///
+    pub fn below(&self, bound: usize) -> usize {
+        assert!(bound != 0, "bound must not be 0");
+
+        // reject the values of the incomplete last interval to avoid a modulo bias
+        let bound = bound as u64;
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    ///
+    /// Moves `count` uniformly chosen elements of `slice` to it's start in random order, the
+    /// remaining elements are left in unspecified order. A `count` of the slice's length shuffles
+    /// it.
+    ///
+    pub fn partial_shuffle<T>(&self, slice: &mut [T], count: usize) {
+        for idx in 0..count.min(slice.len().saturating_sub(1)) {
+            let other = idx + self.below(slice.len() - idx);
+            slice.swap(idx, other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn next_u64() {
+        // reference values of splitmix64 seeded with 0
+        let rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+        assert_eq!(rng.next_u64(), 0x06c4_5d18_8009_454f);
+    }
+
+    #[test]
+    fn reseed() {
+        let rng = Rng::new(42);
+        let first = [rng.next_u64(), rng.next_u64()];
+
+        rng.reseed(42);
+        assert_eq!([rng.next_u64(), rng.next_u64()], first);
+    }
+
+    #[test]
+    fn below() {
+        let rng = Rng::new(7);
+        for bound in 1..50 {
+            assert!(rng.below(bound) < bound);
+        }
+    }
+
+    #[test]
+    fn partial_shuffle() {
+        let rng = Rng::new(7);
+
+        let mut vec = (0..10).collect::<Vec<_>>();
+        rng.partial_shuffle(&mut vec, 10);
+        assert_ne!(vec, (0..10).collect::<Vec<_>>());
+
+        vec.sort_unstable();
+        assert_eq!(vec, (0..10).collect::<Vec<_>>());
+
+        let mut empty: [u8; 0] = [];
+        rng.partial_shuffle(&mut empty, 3);
+    }
+}
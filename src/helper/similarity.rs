@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+
+///
+/// The number of unchanged context lines around the changes of a [`unified_diff`] hunk.
+///
+const DIFF_CONTEXT: usize = 3;
+
+///
+/// Returns the Levenshtein distance between `left` and `right`, the minimum number of [`char`]
+/// insertions, deletions and substitutions to turn one into the other.
+///
+pub fn levenshtein(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut prev = (0..=right.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; right.len() + 1];
+
+    for (i, left_ch) in left.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &right_ch) in right.iter().enumerate() {
+            let cost = if left_ch == right_ch { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[right.len()]
+}
+
+///
+/// Returns the Damerau-Levenshtein distance between `left` and `right`, the minimum number of
+/// [`char`] insertions, deletions, substitutions and transpositions of adjacent [`char`]s to turn
+/// one into the other.
+///
+pub fn damerau(left: &str, right: &str) -> usize {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+    let max = left.len() + right.len();
+
+    // the table is offset by one row and column holding the maximum distance
+    let width = right.len() + 2;
+    let mut dist = vec![0; (left.len() + 2) * width];
+    dist[0] = max;
+    for i in 0..=left.len() {
+        dist[(i + 1) * width] = max;
+        dist[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=right.len() {
+        dist[j + 1] = max;
+        dist[width + j + 1] = j;
+    }
+
+    // the last row each char was seen in
+    let mut last_row = HashMap::new();
+
+    for i in 1..=left.len() {
+        // the last column in this row with a match
+        let mut last_match_col = 0;
+
+        for j in 1..=right.len() {
+            let last_match_row = last_row.get(&right[j - 1]).copied().unwrap_or(0);
+            let last_col = last_match_col;
+
+            let cost = if left[i - 1] == right[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+
+            dist[(i + 1) * width + j + 1] = (dist[i * width + j] + cost)
+                .min(dist[(i + 1) * width + j] + 1)
+                .min(dist[i * width + j + 1] + 1)
+                .min(
+                    dist[last_match_row * width + last_col]
+                        + (i - last_match_row - 1)
+                        + 1
+                        + (j - last_col - 1),
+                );
+        }
+
+        last_row.insert(left[i - 1], i);
+    }
+
+    dist[(left.len() + 1) * width + right.len() + 1]
+}
+
+///
+/// Returns the Jaro-Winkler similarity between `left` and `right`, from `0.0` for no similarity
+/// to `1.0` for equality. Common prefixes of up to 4 [`char`]s are weighted with a scaling factor
+/// of `0.1`.
+///
+pub fn jaro_winkler(left: &str, right: &str) -> f64 {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+
+    let window = (left.len().max(right.len()) / 2).saturating_sub(1);
+    let mut left_matched = vec![false; left.len()];
+    let mut right_matched = vec![false; right.len()];
+    let mut matches = 0;
+
+    for (i, left_ch) in left.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(right.len());
+
+        for j in start..end {
+            if !right_matched[j] && right[j] == *left_ch {
+                left_matched[i] = true;
+                right_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let left_order = left
+        .iter()
+        .zip(&left_matched)
+        .filter_map(|(ch, &matched)| matched.then(|| ch));
+    let right_order = right
+        .iter()
+        .zip(&right_matched)
+        .filter_map(|(ch, &matched)| matched.then(|| ch));
+    let transpositions = left_order
+        .zip(right_order)
+        .filter(|(left, right)| left != right)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    let jaro = (matches / left.len() as f64
+        + matches / right.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0;
+
+    let prefix = left
+        .iter()
+        .zip(&right)
+        .take(4)
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+///
+/// A line of a diff.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Line<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+///
+/// Creates a unified diff of the lines of `left` and `right` using their longest common
+/// subsequence. Every hunk starts with a `@@ -l,s +l,s @@` header, followed by it's lines
+/// prefixed with ` `, `-` or `+` for unchanged, removed or added lines.
+///
+/// ### Returns
+///
+/// The lines of the diff, which are empty if both inputs are equal.
+///
+pub fn unified_diff(left: &str, right: &str) -> Vec<String> {
+    let left = left.lines().collect::<Vec<_>>();
+    let right = right.lines().collect::<Vec<_>>();
+
+    // the length of the longest common subsequence of the remaining lines
+    let width = right.len() + 1;
+    let mut lcs = vec![0usize; (left.len() + 1) * width];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lcs[i * width + j] = if left[i] == right[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            lines.push(Line::Equal(left[i]));
+            i += 1;
+            j += 1;
+        } else if j == right.len()
+            || (i < left.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            lines.push(Line::Delete(left[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Insert(right[j]));
+            j += 1;
+        }
+    }
+
+    let changes = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Equal(_)))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    // group changes which are close enough to share their context
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for idx in changes {
+        match hunks.last_mut() {
+            Some((_, end)) if idx - *end <= DIFF_CONTEXT * 2 + 1 => *end = idx,
+            _ => hunks.push((idx, idx)),
+        }
+    }
+
+    let mut ret = vec![];
+    for (start, end) in hunks {
+        let start = start.saturating_sub(DIFF_CONTEXT);
+        let end = (end + DIFF_CONTEXT + 1).min(lines.len());
+
+        // the 0-based positions of the first line of the hunk in both inputs
+        let (left_pos, right_pos) =
+            lines[..start]
+                .iter()
+                .fold((0, 0), |(left, right), line| match line {
+                    Line::Equal(_) => (left + 1, right + 1),
+                    Line::Delete(_) => (left + 1, right),
+                    Line::Insert(_) => (left, right + 1),
+                });
+
+        let hunk = &lines[start..end];
+        let left_len = hunk
+            .iter()
+            .filter(|line| !matches!(line, Line::Insert(_)))
+            .count();
+        let right_len = hunk
+            .iter()
+            .filter(|line| !matches!(line, Line::Delete(_)))
+            .count();
+
+        // empty ranges start at the line before them
+        let header_pos = |pos: usize, len: usize| if len == 0 { pos } else { pos + 1 };
+        ret.push(format!(
+            "@@ -{},{} +{},{} @@",
+            header_pos(left_pos, left_len),
+            left_len,
+            header_pos(right_pos, right_len),
+            right_len
+        ));
+
+        ret.extend(hunk.iter().map(|line| match line {
+            Line::Equal(line) => format!(" {}", line),
+            Line::Delete(line) => format!("-{}", line),
+            Line::Insert(line) => format!("+{}", line),
+        }));
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn levenshtein() {
+        assert_eq!(super::levenshtein("kitten", "sitting"), 3);
+        assert_eq!(super::levenshtein("", "abc"), 3);
+        assert_eq!(super::levenshtein("abc", "abc"), 0);
+        assert_eq!(super::levenshtein("ca", "ac"), 2);
+        assert_eq!(super::levenshtein("über", "uber"), 1);
+    }
+
+    #[test]
+    fn damerau() {
+        assert_eq!(super::damerau("kitten", "sitting"), 3);
+        assert_eq!(super::damerau("ca", "ac"), 1);
+        assert_eq!(super::damerau("ca", "abc"), 2);
+        assert_eq!(super::damerau("", ""), 0);
+        assert_eq!(super::damerau("abcdef", "badcfe"), 3);
+    }
+
+    #[test]
+    fn jaro_winkler() {
+        let round = |value: f64| (value * 1000.0).round() / 1000.0;
+
+        assert_eq!(round(super::jaro_winkler("martha", "marhta")), 0.961);
+        assert_eq!(round(super::jaro_winkler("dixon", "dicksonx")), 0.813);
+        assert_eq!(round(super::jaro_winkler("dwayne", "duane")), 0.84);
+        assert_eq!(super::jaro_winkler("abc", "abc"), 1.0);
+        assert_eq!(super::jaro_winkler("abc", "xyz"), 0.0);
+        assert_eq!(super::jaro_winkler("", ""), 1.0);
+    }
+
+    #[test]
+    fn unified_diff() {
+        assert!(super::unified_diff("a\nb", "a\nb").is_empty());
+        assert_eq!(
+            super::unified_diff("a\nb\nc", "a\nx\nc\nd"),
+            ["@@ -1,3 +1,4 @@", " a", "-b", "+x", " c", "+d"]
+        );
+        assert_eq!(super::unified_diff("", "a"), ["@@ -0,0 +1,1 @@", "+a"]);
+
+        let left = (1..=12).map(|n| n.to_string()).collect::<Vec<_>>();
+        let mut right = left.clone();
+        right[1] = "two".to_owned();
+        right.remove(10);
+        assert_eq!(
+            super::unified_diff(&left.join("\n"), &right.join("\n")),
+            [
+                "@@ -1,5 +1,5 @@",
+                " 1",
+                "-2",
+                "+two",
+                " 3",
+                " 4",
+                " 5",
+                "@@ -8,5 +8,4 @@",
+                " 8",
+                " 9",
+                " 10",
+                "-11",
+                " 12"
+            ]
+        );
+    }
+}
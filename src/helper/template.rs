@@ -0,0 +1,218 @@
+///
+/// The highest placeholder index of a [`Template`], far beyond the length of any real queue.
+///
+const MAX_INDEX: usize = u16::MAX as usize;
+
+///
+/// An error which occurs when parsing a [`Template`].
+///
+#[derive(thiserror::Error, Debug)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub enum TemplateError {
+    ///
+    /// A placeholder was opened but not closed. Contains the byte offset of the opening brace.
+    ///
+    #[error("unclosed placeholder at byte {0}")]
+    Unclosed(usize),
+
+    ///
+    /// A closing brace was neither escaped nor closing a placeholder. Contains it's byte offset.
+    ///
+    #[error("unmatched '}}' at byte {0}")]
+    Unmatched(usize),
+
+    ///
+    /// A placeholder index was not a valid number or greater than [`MAX_INDEX`]. Contains the
+    /// byte offset of the opening brace.
+    ///
+    #[error("invalid placeholder index at byte {0}")]
+    Index(usize),
+}
+
+///
+/// A part of a [`Template`].
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Segment<'a> {
+    ///
+    /// Text which is copied verbatim.
+    ///
+    Literal(&'a str),
+
+    ///
+    /// A placeholder for the argument with the given index and an optional separator.
+    ///
+    Placeholder(usize, Option<&'a str>),
+}
+
+///
+/// A parsed template with positional placeholders.
+///
+/// * `{0}`, `{1}`, ... refer to the argument with the given index
+/// * `{}` refers to the argument after the last implicit one, starting with `0`
+/// * `{:sep}` and `{0:sep}` additionally pass the separator `sep` to the argument formatting
+/// * `{{` and `}}` are escaped braces
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Template<'a> {
+    segments: Vec<Segment<'a>>,
+}
+
+impl<'a> Template<'a> {
+    ///
+    /// Parses the given `input` template.
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the template was valid, contains the parsed [`Template`]
+    /// * [`Err`]
+    ///   * the template contained an unclosed, unmatched or invalid placeholder
+    ///
+    pub fn parse(input: &'a str) -> Result<Self, TemplateError> {
+        let bytes = input.as_bytes();
+        let mut segments = vec![];
+        let mut implicit = 0;
+        let mut start = 0;
+        let mut idx = 0;
+
+        while idx < bytes.len() {
+            match bytes[idx] {
+                brace @ (b'{' | b'}') => {
+                    if start < idx {
+                        segments.push(Segment::Literal(&input[start..idx]));
+                    }
+
+                    if bytes.get(idx + 1) == Some(&brace) {
+                        segments.push(Segment::Literal(&input[idx..idx + 1]));
+                        idx += 2;
+                    } else if brace == b'}' {
+                        return Err(TemplateError::Unmatched(idx));
+                    } else {
+                        let len = input[idx..].find('}').ok_or(TemplateError::Unclosed(idx))?;
+                        let inner = &input[idx + 1..idx + len];
+
+                        let (index, sep) = match inner.split_once(':') {
+                            Some((index, sep)) => (index, Some(sep)),
+                            None => (inner, None),
+                        };
+
+                        let index = if index.is_empty() {
+                            implicit += 1;
+                            Some(implicit - 1)
+                        } else {
+                            index.parse().ok()
+                        }
+                        .filter(|&index| index <= MAX_INDEX)
+                        .ok_or(TemplateError::Index(idx))?;
+
+                        segments.push(Segment::Placeholder(index, sep));
+                        idx += len + 1;
+                    }
+
+                    start = idx;
+                }
+                _ => idx += 1,
+            }
+        }
+
+        if start < bytes.len() {
+            segments.push(Segment::Literal(&input[start..]));
+        }
+
+        Ok(Self { segments })
+    }
+
+    ///
+    /// Returns the number of arguments required to render this [`Template`], which is one more
+    /// than the highest referenced index.
+    ///
+    pub fn arity(&self) -> usize {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Placeholder(index, _) => Some(index + 1),
+                Segment::Literal(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    ///
+    /// Renders this [`Template`] by replacing every placeholder with it's argument formatted by
+    /// `fmt`, which receives the placeholder's separator.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `args` is shorter than the [`Template::arity`].
+    ///
+    pub fn render<T>(&self, args: &[T], fmt: impl Fn(&T, Option<&str>) -> String) -> String {
+        let mut ret = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => ret.push_str(literal),
+                Segment::Placeholder(index, sep) => ret.push_str(&fmt(&args[*index], *sep)),
+            }
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rendered(template: &str, args: &[&str]) -> String {
+        Template::parse(template).unwrap().render(args, |arg, sep| {
+            format!("{}{}", arg, sep.unwrap_or_default())
+        })
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Template::parse("").unwrap().arity(), 0);
+        assert_eq!(Template::parse("{} {}").unwrap().arity(), 2);
+        assert_eq!(Template::parse("{2} {}").unwrap().arity(), 3);
+        assert_eq!(Template::parse("{{}}").unwrap().arity(), 0);
+
+        assert_eq!(
+            Template::parse("a {").unwrap_err(),
+            TemplateError::Unclosed(2)
+        );
+        assert_eq!(
+            Template::parse("a } b").unwrap_err(),
+            TemplateError::Unmatched(2)
+        );
+        assert_eq!(Template::parse("{x}").unwrap_err(), TemplateError::Index(0));
+        assert_eq!(
+            Template::parse("{-1}").unwrap_err(),
+            TemplateError::Index(0)
+        );
+        assert_eq!(
+            Template::parse(&format!("{{{}}}", MAX_INDEX))
+                .unwrap()
+                .arity(),
+            MAX_INDEX + 1
+        );
+        assert_eq!(
+            Template::parse(&format!("a{{{}}}", MAX_INDEX + 1)).unwrap_err(),
+            TemplateError::Index(1)
+        );
+        assert_eq!(
+            Template::parse(&format!("a{{{}}}", usize::MAX)).unwrap_err(),
+            TemplateError::Index(1)
+        );
+    }
+
+    #[test]
+    fn render() {
+        assert_eq!(rendered("plain", &[]), "plain");
+        assert_eq!(rendered("{} and {}", &["a", "b"]), "a and b");
+        assert_eq!(rendered("{1}{0}{1}", &["a", "b"]), "bab");
+        assert_eq!(rendered("{}{0}{}", &["a", "b"]), "aab");
+        assert_eq!(rendered("{{{}}}", &["a"]), "{a}");
+        assert_eq!(rendered("{:, }|{0:}", &["a"]), "a, |a");
+        assert_eq!(rendered("ü{}ö", &["ä"]), "üäö");
+    }
+}
@@ -0,0 +1,235 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+///
+/// The replacement for [`char`]s without an ascii transliteration.
+///
+const UNKNOWN: &str = "?";
+
+///
+/// Transliterations of non-ascii [`char`]s which do not decompose into an ascii [`char`] and
+/// combining marks.
+///
+const TRANSLITERATIONS: &[(char, &str)] = &[
+    ('ß', "ss"),
+    ('ẞ', "SS"),
+    ('æ', "ae"),
+    ('Æ', "AE"),
+    ('œ', "oe"),
+    ('Œ', "OE"),
+    ('ø', "o"),
+    ('Ø', "O"),
+    ('đ', "d"),
+    ('Đ', "D"),
+    ('ð', "d"),
+    ('Ð', "D"),
+    ('þ', "th"),
+    ('Þ', "TH"),
+    ('ł', "l"),
+    ('Ł', "L"),
+    ('ı', "i"),
+    ('ŋ', "ng"),
+    ('Ŋ', "NG"),
+    ('ħ', "h"),
+    ('Ħ', "H"),
+    ('ŧ', "t"),
+    ('Ŧ', "T"),
+    ('\u{a0}', " "),
+    ('‘', "'"),
+    ('’', "'"),
+    ('‚', "'"),
+    ('“', "\""),
+    ('”', "\""),
+    ('„', "\""),
+    ('«', "<<"),
+    ('»', ">>"),
+    ('‐', "-"),
+    ('‑', "-"),
+    ('‒', "-"),
+    ('–', "-"),
+    ('—', "-"),
+    ('−', "-"),
+    ('•', "*"),
+    ('×', "x"),
+    ('÷', "/"),
+    ('€', "EUR"),
+    ('£', "GBP"),
+    ('©', "(C)"),
+    ('®', "(R)"),
+];
+
+///
+/// An extension trait for unicode normalization and transliteration of strings.
+///
+pub trait UnicodeExt {
+    ///
+    /// Converts [`self`] to the canonical composition normalization form `NFC`.
+    ///
+    fn into_nfc(self) -> Self;
+
+    ///
+    /// Converts [`self`] to the canonical decomposition normalization form `NFD`.
+    ///
+    fn into_nfd(self) -> Self;
+
+    ///
+    /// Converts [`self`] to the compatibility composition normalization form `NFKC`.
+    ///
+    fn into_nfkc(self) -> Self;
+
+    ///
+    /// Converts [`self`] to the compatibility decomposition normalization form `NFKD`.
+    ///
+    fn into_nfkd(self) -> Self;
+
+    ///
+    /// Removes all diacritics from [`self`], the result is in `NFC`.
+    ///
+    fn into_stripped(self) -> Self;
+
+    ///
+    /// Transliterates [`self`] to ascii, [`char`]s without a transliteration are replaced by `?`.
+    ///
+    fn into_ascii(self) -> Self;
+}
+
+impl UnicodeExt for String {
+    fn into_nfc(self) -> Self {
+        self.nfc().collect()
+    }
+
+    fn into_nfd(self) -> Self {
+        self.nfd().collect()
+    }
+
+    fn into_nfkc(self) -> Self {
+        self.nfkc().collect()
+    }
+
+    fn into_nfkd(self) -> Self {
+        self.nfkd().collect()
+    }
+
+    fn into_stripped(self) -> Self {
+        self.nfd()
+            .filter(|&ch| !is_combining_mark(ch))
+            .nfc()
+            .collect()
+    }
+
+    fn into_ascii(self) -> Self {
+        let mut ret = String::with_capacity(self.len());
+
+        // transliterate before decomposing, some transliterated chars would otherwise decompose
+        for ch in self.chars() {
+            if ch.is_ascii() {
+                ret.push(ch);
+            } else if let Some((_, ascii)) = TRANSLITERATIONS.iter().find(|(key, _)| *key == ch) {
+                ret.push_str(ascii);
+            } else {
+                let mut decomposed = Some(ch)
+                    .into_iter()
+                    .nfkd()
+                    .filter(|&ch| !is_combining_mark(ch))
+                    .peekable();
+
+                // chars which decompose into marks only are dropped
+                if decomposed.peek().is_none() {
+                    continue;
+                }
+
+                for ch in decomposed {
+                    if ch.is_ascii() {
+                        ret.push(ch);
+                    } else if let Some((_, ascii)) =
+                        TRANSLITERATIONS.iter().find(|(key, _)| *key == ch)
+                    {
+                        ret.push_str(ascii);
+                    } else {
+                        ret.push_str(UNKNOWN);
+                    }
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    ///
+    /// The inputs and their expected `NFC`, `NFD`, `NFKC` and `NFKD` forms.
+    ///
+    const NORMALIZATION: &[[&str; 5]] = &[
+        ["e\u{301}", "é", "e\u{301}", "é", "e\u{301}"],
+        ["é", "é", "e\u{301}", "é", "e\u{301}"],
+        ["Å", "Å", "A\u{30a}", "Å", "A\u{30a}"],
+        ["ﬁ", "ﬁ", "ﬁ", "fi", "fi"],
+        ["x²", "x²", "x²", "x2", "x2"],
+        [
+            "ḱ\u{323}",
+            "ḳ\u{301}",
+            "k\u{323}\u{301}",
+            "ḳ\u{301}",
+            "k\u{323}\u{301}",
+        ],
+        [
+            "한",
+            "한",
+            "\u{1112}\u{1161}\u{11ab}",
+            "한",
+            "\u{1112}\u{1161}\u{11ab}",
+        ],
+    ];
+
+    ///
+    /// The inputs and their expected forms without diacritics.
+    ///
+    const STRIPPED: &[[&str; 2]] = &[
+        ["Crème Brûlée", "Creme Brulee"],
+        ["e\u{301}\u{302}", "e"],
+        ["Ångström", "Angstrom"],
+        ["ß æ", "ß æ"],
+        ["ﬁ", "ﬁ"],
+    ];
+
+    ///
+    /// The inputs and their expected ascii transliterations.
+    ///
+    const ASCII: &[[&str; 2]] = &[
+        ["Straße", "Strasse"],
+        ["Crème Brûlée", "Creme Brulee"],
+        ["Łódź", "Lodz"],
+        ["Ærøskøbing", "AEroskobing"],
+        ["“quoted” – text…", "\"quoted\" - text..."],
+        ["ﬁx²", "fix2"],
+        ["日本", "??"],
+        ["a\u{301}", "a"],
+    ];
+
+    #[test]
+    fn string_into_normalized() {
+        for [input, nfc, nfd, nfkc, nfkd] in NORMALIZATION {
+            assert_eq!(&input.to_string().into_nfc(), nfc, "nfc of {:?}", input);
+            assert_eq!(&input.to_string().into_nfd(), nfd, "nfd of {:?}", input);
+            assert_eq!(&input.to_string().into_nfkc(), nfkc, "nfkc of {:?}", input);
+            assert_eq!(&input.to_string().into_nfkd(), nfkd, "nfkd of {:?}", input);
+        }
+    }
+
+    #[test]
+    fn string_into_stripped() {
+        for [input, expected] in STRIPPED {
+            assert_eq!(&input.to_string().into_stripped(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn string_into_ascii() {
+        for [input, expected] in ASCII {
+            assert_eq!(&input.to_string().into_ascii(), expected, "{:?}", input);
+        }
+    }
+}
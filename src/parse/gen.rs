@@ -100,7 +100,8 @@ pub struct EagerGen<'t> {
 
 impl<'a> EagerGen<'a> {
     ///
-    /// Attempts creating a new [`EagerGen`] for the given `input` string.
+    /// Attempts creating a new [`EagerGen`] for the given `input` string, the sub-programs of
+    /// block tokens are parsed eagerly too.
     ///
     pub fn new(input: &'a str) -> color_eyre::Result<Self> {
         let mut lazy = LazyGen::new(input);
@@ -108,7 +109,13 @@ impl<'a> EagerGen<'a> {
 
         loop {
             match Pin::new(&mut lazy).resume(()) {
-                GeneratorState::Yielded(yielded) => tokens.push(yielded),
+                GeneratorState::Yielded(yielded) => {
+                    if let Some(block) = yielded.0.block() {
+                        Self::new(block)?;
+                    }
+
+                    tokens.push(yielded);
+                }
                 GeneratorState::Complete(res) => {
                     res?;
                     break;
@@ -231,6 +238,33 @@ mod test {
         EagerGen::new("<//|.").expect_err("program is invalid");
     }
 
+    #[test]
+    fn eager_block() {
+        let mut gen = EagerGen::new("<{(*~)}>").expect("the program is valid");
+
+        let mut yields = vec![];
+        for _ in 0..3 {
+            yields.push(match Pin::new(&mut gen).resume(()) {
+                GeneratorState::Yielded(yielded) => yielded,
+                _ => panic!("less than 3 tokens yielded"),
+            });
+        }
+
+        match Pin::new(&mut gen).resume(()) {
+            GeneratorState::Complete(res) => res.expect("program is valid"),
+            _ => panic!("more than 3 tokens yielded"),
+        };
+
+        assert_eq!(yields[0], (Token::In, 1));
+        assert_eq!(yields[1], (Token::MapBlock("*~"), 6));
+        assert_eq!(yields[2], (Token::Out, 1));
+    }
+
+    #[test]
+    fn eager_block_error() {
+        EagerGen::new("<{(*|.)}>").expect_err("sub-program is invalid");
+    }
+
     #[test]
     fn lazy_reset() {
         let mut gen = LazyGen::new("<//|.|>");
@@ -5,6 +5,10 @@ pub use gen::LazyGen;
 
 mod token;
 pub use token::next_token;
+pub use token::Cast as CastToken;
 pub use token::Check as CheckToken;
+pub use token::Extremum as ExtremumToken;
 pub use token::Map as MapToken;
+pub use token::Metric as MetricToken;
+pub use token::Radix as RadixToken;
 pub use token::Token;
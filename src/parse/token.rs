@@ -1,11 +1,11 @@
-use crate::helper::{EolinaIndex, EolinaRange};
+use crate::helper::{Encoding, EolinaIndex, EolinaRange, Format, Glob, Literal, Template};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_till},
     character::complete::{digit0, digit1},
-    combinator::opt,
-    error::Error as NomError,
-    sequence::{delimited, pair, separated_pair},
+    combinator::{map, opt, recognize},
+    error::{Error as NomError, ErrorKind},
+    sequence::{delimited, pair, preceded, separated_pair, tuple},
     Err as NomErr,
 };
 use std::fmt::{self, Display, Formatter};
@@ -47,11 +47,166 @@ impl Display for Check {
     }
 }
 
+///
+/// The radix of a textual char code.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Radix {
+    ///
+    /// The decimal radix `d`.
+    ///
+    Dec,
+
+    ///
+    /// The hexadecimal radix `x`.
+    ///
+    Hex,
+
+    ///
+    /// The binary radix `b`.
+    ///
+    Bin,
+}
+
+impl Radix {
+    ///
+    /// Returns the numeric base of this [`Radix`].
+    ///
+    pub fn base(&self) -> u32 {
+        match self {
+            Self::Dec => 10,
+            Self::Hex => 16,
+            Self::Bin => 2,
+        }
+    }
+}
+
+impl Display for Radix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dec => f.write_str("d"),
+            Self::Hex => f.write_str("x"),
+            Self::Bin => f.write_str("b"),
+        }
+    }
+}
+
+///
+/// A string similarity metric.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Metric {
+    ///
+    /// The Levenshtein distance `l`.
+    ///
+    Levenshtein,
+
+    ///
+    /// The Damerau-Levenshtein distance `d`.
+    ///
+    Damerau,
+
+    ///
+    /// The Jaro-Winkler similarity `j`.
+    ///
+    JaroWinkler,
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Levenshtein => f.write_str("l"),
+            Self::Damerau => f.write_str("d"),
+            Self::JaroWinkler => f.write_str("j"),
+        }
+    }
+}
+
+///
+/// An element selected from a list by comparison.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Extremum {
+    ///
+    /// The lexicographic minimum `<`.
+    ///
+    Min,
+
+    ///
+    /// The lexicographic maximum `>`.
+    ///
+    Max,
+
+    ///
+    /// The element with the fewest [`char`]s `-`.
+    ///
+    Shortest,
+
+    ///
+    /// The element with the most [`char`]s `+`.
+    ///
+    Longest,
+}
+
+impl Display for Extremum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Min => f.write_str("<"),
+            Self::Max => f.write_str(">"),
+            Self::Shortest => f.write_str("-"),
+            Self::Longest => f.write_str("+"),
+        }
+    }
+}
+
+///
+/// A conversion between [`Value`](crate::program::Value) kinds.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cast {
+    ///
+    /// The string cast `s`.
+    ///
+    String,
+
+    ///
+    /// The list cast `l`.
+    ///
+    List,
+
+    ///
+    /// The bool cast `?`.
+    ///
+    Bool,
+
+    ///
+    /// The number cast `n`.
+    ///
+    Number,
+
+    ///
+    /// The kind cast `k`.
+    ///
+    Kind,
+}
+
+impl Display for Cast {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => f.write_str("s"),
+            Self::List => f.write_str("l"),
+            Self::Bool => f.write_str("?"),
+            Self::Number => f.write_str("n"),
+            Self::Kind => f.write_str("k"),
+        }
+    }
+}
+
 ///
 /// A filter or map token, a token between `{` and `}`.
 ///
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum Map {
+pub enum Map<'p> {
     ///
     /// The lowercase map `_`.
     ///
@@ -66,14 +221,207 @@ pub enum Map {
     /// The swap case map `%`.
     ///
     Swap,
+
+    ///
+    /// The title case map `t`.
+    ///
+    Title,
+
+    ///
+    /// The capitalize first map `f`.
+    ///
+    Capitalize,
+
+    ///
+    /// The snake case map `s`.
+    ///
+    Snake,
+
+    ///
+    /// The screaming snake case map `S`.
+    ///
+    Screaming,
+
+    ///
+    /// The kebab case map `k`.
+    ///
+    Kebab,
+
+    ///
+    /// The camel case map `c`.
+    ///
+    Camel,
+
+    ///
+    /// The pascal case map `p`.
+    ///
+    Pascal,
+
+    ///
+    /// The canonical composition map `nfc`.
+    ///
+    Nfc,
+
+    ///
+    /// The canonical decomposition map `nfd`.
+    ///
+    Nfd,
+
+    ///
+    /// The compatibility composition map `nfkc`.
+    ///
+    Nfkc,
+
+    ///
+    /// The compatibility decomposition map `nfkd`.
+    ///
+    Nfkd,
+
+    ///
+    /// The diacritic removal map `nodia`.
+    ///
+    NoDiacritics,
+
+    ///
+    /// The ascii transliteration map `ascii`.
+    ///
+    Ascii,
+
+    ///
+    /// The trim map `-` or `-"x"` where `x` are the [`char`]s to trim instead of whitespace.
+    ///
+    Trim(Option<&'p str>),
+
+    ///
+    /// The trim start map `-<` or `-<"x"` where `x` are the [`char`]s to trim instead of
+    /// whitespace.
+    ///
+    TrimStart(Option<&'p str>),
+
+    ///
+    /// The trim end map `->` or `->"x"` where `x` are the [`char`]s to trim instead of
+    /// whitespace.
+    ///
+    TrimEnd(Option<&'p str>),
+
+    ///
+    /// The left pad map `<x` or `<x"c"` where `x` is the [`usize`] width and `c` the fill [`char`]
+    /// instead of a space.
+    ///
+    PadLeft(usize, char),
+
+    ///
+    /// The right pad map `>x` or `>x"c"` where `x` is the [`usize`] width and `c` the fill
+    /// [`char`] instead of a space.
+    ///
+    PadRight(usize, char),
+
+    ///
+    /// The center pad map `=x` or `=x"c"` where `x` is the [`usize`] width and `c` the fill
+    /// [`char`] instead of a space.
+    ///
+    PadCenter(usize, char),
+
+    ///
+    /// The truncate map `.x` where `x` is the [`usize`] width including the ellipsis.
+    ///
+    Truncate(usize),
+
+    ///
+    /// The rotation map `r13` or `r:x` where `x` is the [`isize`] shift of ascii letters.
+    ///
+    Rotate(isize),
+
+    ///
+    /// The atbash map `atbash`.
+    ///
+    Atbash,
+
+    ///
+    /// The char code map `ord` or `ord:x` where `x` is the [`Radix`] of the code, defaulting to
+    /// [`Radix::Dec`].
+    ///
+    Ord(Radix),
+
+    ///
+    /// The char map `chr` or `chr:x` where `x` is the [`Radix`] of the code, defaulting to
+    /// [`Radix::Dec`].
+    ///
+    Chr(Radix),
+
+    ///
+    /// The encode map `x` where `x` is an [`Encoding`].
+    ///
+    Encode(Encoding),
+
+    ///
+    /// The decode map `!x` where `x` is an [`Encoding`].
+    ///
+    Decode(Encoding),
+
+    ///
+    /// The escape map `x` where `x` is a [`Format`].
+    ///
+    Escape(Format),
+
+    ///
+    /// The unescape map `!x` where `x` is a [`Format`].
+    ///
+    Unescape(Format),
+
+    ///
+    /// The run-length encode map `rle`.
+    ///
+    RunLength,
+
+    ///
+    /// The run-length decode map `!rle`.
+    ///
+    RunLengthDecode,
 }
 
-impl Display for Map {
+impl<'p> Display for Map<'p> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Lower => f.write_str("{_}"),
             Self::Upper => f.write_str("{^}"),
             Self::Swap => f.write_str("{%}"),
+            Self::Title => f.write_str("{t}"),
+            Self::Capitalize => f.write_str("{f}"),
+            Self::Snake => f.write_str("{s}"),
+            Self::Screaming => f.write_str("{S}"),
+            Self::Kebab => f.write_str("{k}"),
+            Self::Camel => f.write_str("{c}"),
+            Self::Pascal => f.write_str("{p}"),
+            Self::Nfc => f.write_str("{nfc}"),
+            Self::Nfd => f.write_str("{nfd}"),
+            Self::Nfkc => f.write_str("{nfkc}"),
+            Self::Nfkd => f.write_str("{nfkd}"),
+            Self::NoDiacritics => f.write_str("{nodia}"),
+            Self::Ascii => f.write_str("{ascii}"),
+            Self::Trim(None) => f.write_str("{-}"),
+            Self::Trim(Some(chars)) => write!(f, "{{-{:?}}}", chars),
+            Self::TrimStart(None) => f.write_str("{-<}"),
+            Self::TrimStart(Some(chars)) => write!(f, "{{-<{:?}}}", chars),
+            Self::TrimEnd(None) => f.write_str("{->}"),
+            Self::TrimEnd(Some(chars)) => write!(f, "{{->{:?}}}", chars),
+            Self::PadLeft(width, fill) => write!(f, "{{<{}{:?}}}", width, fill.to_string()),
+            Self::PadRight(width, fill) => write!(f, "{{>{}{:?}}}", width, fill.to_string()),
+            Self::PadCenter(width, fill) => write!(f, "{{={}{:?}}}", width, fill.to_string()),
+            Self::Truncate(width) => write!(f, "{{.{}}}", width),
+            Self::Rotate(13) => f.write_str("{r13}"),
+            Self::Rotate(shift) => write!(f, "{{r:{}}}", shift),
+            Self::Atbash => f.write_str("{atbash}"),
+            Self::Ord(Radix::Dec) => f.write_str("{ord}"),
+            Self::Ord(radix) => write!(f, "{{ord:{}}}", radix),
+            Self::Chr(Radix::Dec) => f.write_str("{chr}"),
+            Self::Chr(radix) => write!(f, "{{chr:{}}}", radix),
+            Self::Encode(encoding) => write!(f, "{{{}}}", encoding),
+            Self::Decode(encoding) => write!(f, "{{!{}}}", encoding),
+            Self::Escape(format) => write!(f, "{{{}}}", format),
+            Self::Unescape(format) => write!(f, "{{!{}}}", format),
+            Self::RunLength => f.write_str("{rle}"),
+            Self::RunLengthDecode => f.write_str("{!rle}"),
         }
     }
 }
@@ -88,15 +436,25 @@ pub enum Token<'p> {
     ///
     In,
 
+    ///
+    /// The rest input token `<:s` reading all remaining input as one [`String`].
+    ///
+    InString,
+
+    ///
+    /// The rest input token `<:l` reading all remaining input as lines.
+    ///
+    InLines,
+
     ///
     /// The output token `>`.
     ///
     Out,
 
     ///
-    /// The split token `/x/` where `x` is empty or a [`str`].
+    /// The split token `/x/` where `x` is empty or a [`Literal`].
     ///
-    Split(Option<&'p str>),
+    Split(Option<Literal<'p>>),
 
     ///
     /// The join token `.`.
@@ -139,97 +497,493 @@ pub enum Token<'p> {
     Rotate(usize),
 
     ///
-    /// A check token `{x}` where `x` is a [`Map`] token.
+    /// The repeat token `xn` where `n` is empty or a [`usize`], if empty the count is popped off
+    /// the queue after the value.
     ///
-    Map(Map),
+    Repeat(Option<usize>),
 
     ///
-    /// The filter token `[x]` where `x` is a [`Check`] token.
+    /// The sequence token `,|x.y|` or `,|x.y.s|` where `x` is the inclusive [`isize`] start, `y`
+    /// the exclusive [`isize`] end and `s` the [`usize`] step, defaulting to `1`.
     ///
-    Filter(Check),
+    Sequence(isize, isize, usize),
 
     ///
-    /// The index token `|x|` where `x` is a [`isize`].
+    /// The char range token `,|"x"."y"|` where `x` and `y` are the inclusive [`char`] bounds, or
+    /// an alphabet `,a`, `,A` or `,d`.
     ///
-    Index(EolinaIndex),
+    CharRange(char, char),
 
     ///
-    /// The slice token `|x.x|` where `x` are empty or [`isize`].
+    /// The random choice token `?`.
     ///
-    Slice(EolinaRange),
-}
+    Choice,
 
-impl<'p> Display for Token<'p> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::In => f.write_str("<"),
-            Self::Out => f.write_str(">"),
-            Self::Split(split) => match split {
-                Some(string) => write!(f, "/{:?}/", string),
-                None => f.write_str("//"),
-            },
-            Self::Join => f.write_str("."),
-            Self::Concat => f.write_str("~"),
-            Self::Copy => f.write_str("*"),
-            Self::IsVowel => f.write_str("v"),
-            Self::IsConso => f.write_str("c"),
-            Self::Rotate(num) => write!(f, "@{}", num),
-            Self::IsUpper => f.write_str("^"),
-            Self::IsLower => f.write_str("_"),
-            Self::Map(map) => map.fmt(f),
-            Self::Filter(filter) => filter.fmt(f),
-            Self::Index(idx) => write!(f, "|{}|", idx),
-            Self::Slice(range) => range.fmt(f),
-        }
-    }
-}
+    ///
+    /// The random sample token `?x` where `x` is the [`usize`] count.
+    ///
+    Sample(usize),
 
-///
-/// Finds the next token inside the given `input` string.
-///
-/// ### Returns
-///
-/// * [`Ok`]
-///   * the `input` starts with a valid token, contains the unparsed rest of
-///     the string after the parsed token and the parsed [`Token`]
-/// * [`Err`]
-///   * unable to parse a token, contains the [`Error`]
-///
-pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
-    // ignore whitespace, treat whitespace as empty
-    let trimmed = input.trim_start_matches(|ch: char| ch.is_ascii_whitespace());
-    if trimmed.is_empty() {
-        color_eyre::eyre::bail!("the given program was empty");
-    }
+    ///
+    /// The shuffle token `??`.
+    ///
+    Shuffle,
 
-    let tirmlen = input.len() - trimmed.len();
+    ///
+    /// The wrap token `wx` where `x` is the [`usize`] width.
+    ///
+    Wrap(usize),
 
-    let single = (
-        tag("<"),
-        tag(">"),
-        tag("."),
-        tag("~"),
-        tag("*"),
-        tag("v"),
-        tag("c"),
-        tag("_"),
-        tag("^"),
-    );
+    ///
+    /// The indent token `ix` where `x` is the [`usize`] number of spaces.
+    ///
+    Indent(usize),
 
-    let double = (pair(tag("@"), digit0),);
+    ///
+    /// The literal indent token `i"x"` where `x` is a [`str`].
+    ///
+    IndentLiteral(&'p str),
+
+    ///
+    /// The table token `t` or `t"x"` where `x` is a [`str`] of column alignments followed by the
+    /// column separator.
+    ///
+    Table(Option<&'p str>),
+
+    ///
+    /// The markdown table token `T` or `T"x"` where `x` is a [`str`] of column alignments.
+    ///
+    Markdown(Option<&'p str>),
+
+    ///
+    /// The dedent token `d`.
+    ///
+    Dedent,
+
+    ///
+    /// A check token `{x}` where `x` is a [`Map`] token.
+    ///
+    Map(Map<'p>),
+
+    ///
+    /// The filter token `[x]` where `x` is a [`Check`] token.
+    ///
+    Filter(Check),
+
+    ///
+    /// The glob check token `g"x"` where `x` is a [`Glob`] [`str`].
+    ///
+    IsGlob(&'p str),
+
+    ///
+    /// The glob filter token `[g"x"]` where `x` is a [`Glob`] [`str`].
+    ///
+    FilterGlob(&'p str),
+
+    ///
+    /// The partition token `%[x]` where `x` is a [`Check`] token.
+    ///
+    Partition(Check),
+
+    ///
+    /// The group token `%=`.
+    ///
+    Group,
+
+    ///
+    /// The select token `;x` where `x` is an [`Extremum`].
+    ///
+    Select(Extremum),
+
+    ///
+    /// The select index token `;#x` where `x` is an [`Extremum`].
+    ///
+    SelectIndex(Extremum),
+
+    ///
+    /// The count token `#[x]` where `x` is a [`Check`] token.
+    ///
+    Count(Check),
+
+    ///
+    /// The literal count token `#x` where `x` is a [`Literal`].
+    ///
+    CountLiteral(Literal<'p>),
+
+    ///
+    /// The frequency token `#`.
+    ///
+    Frequency,
+
+    ///
+    /// The find token `|x|` where `x` is a [`Literal`].
+    ///
+    Find(Literal<'p>),
+
+    ///
+    /// The reverse find token `|-x|` where `x` is a [`Literal`].
+    ///
+    RFind(Literal<'p>),
+
+    ///
+    /// The equals token `==x` where `x` is a [`Literal`].
+    ///
+    Equals(Literal<'p>),
+
+    ///
+    /// The contains token `=?x` where `x` is a [`Literal`].
+    ///
+    Contains(Literal<'p>),
+
+    ///
+    /// The dedupe token `%%`, or `%%I` ignoring case.
+    ///
+    Dedupe(bool),
+
+    ///
+    /// The similarity token `=x` where `x` is a [`Metric`].
+    ///
+    Similarity(Metric),
+
+    ///
+    /// The diff token `=u`.
+    ///
+    Diff,
+
+    ///
+    /// The template token `$"x"` where `x` is a [`Template`] [`str`].
+    ///
+    Template(&'p str),
+
+    ///
+    /// The char codes token `'x` where `x` is the [`Radix`] of the codes.
+    ///
+    Codes(Radix),
+
+    ///
+    /// The chars token `'!x` where `x` is the [`Radix`] of the codes.
+    ///
+    Chars(Radix),
+
+    ///
+    /// The cast token `'x` where `x` is a [`Cast`].
+    ///
+    Cast(Cast),
+
+    ///
+    /// The block map token `{(x)}` where `x` is a sub-program.
+    ///
+    MapBlock(&'p str),
+
+    ///
+    /// The block filter token `[(x)]` where `x` is a sub-program.
+    ///
+    FilterBlock(&'p str),
+
+    ///
+    /// The fold token `&(x)` where `x` is a sub-program.
+    ///
+    Fold(&'p str),
+
+    ///
+    /// The index token `|x|` where `x` is a [`isize`].
+    ///
+    Index(EolinaIndex),
+
+    ///
+    /// The slice token `|x.x|` where `x` are empty or [`isize`].
+    ///
+    Slice(EolinaRange),
+
+    ///
+    /// The insert token `+|x|` where `x` is a [`isize`].
+    ///
+    Insert(EolinaIndex),
+
+    ///
+    /// The remove token `-|x|` where `x` is a [`isize`].
+    ///
+    Remove(EolinaIndex),
+
+    ///
+    /// The remove slice token `-|x.x|` where `x` are empty or [`isize`].
+    ///
+    RemoveSlice(EolinaRange),
+
+    ///
+    /// The replace token `=|x|` where `x` is a [`isize`].
+    ///
+    Replace(EolinaIndex),
+
+    ///
+    /// The replace slice token `=|x.x|` where `x` are empty or [`isize`].
+    ///
+    ReplaceSlice(EolinaRange),
+}
+
+impl<'p> Display for Token<'p> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::In => f.write_str("<"),
+            Self::InString => f.write_str("<:s"),
+            Self::InLines => f.write_str("<:l"),
+            Self::Out => f.write_str(">"),
+            Self::Split(split) => match split {
+                Some(literal) => write!(f, "/{}/", literal),
+                None => f.write_str("//"),
+            },
+            Self::Join => f.write_str("."),
+            Self::Concat => f.write_str("~"),
+            Self::Copy => f.write_str("*"),
+            Self::IsVowel => f.write_str("v"),
+            Self::IsConso => f.write_str("c"),
+            Self::Rotate(num) => write!(f, "@{}", num),
+            Self::Repeat(Some(count)) => write!(f, "x{}", count),
+            Self::Repeat(None) => f.write_str("x"),
+            Self::Sequence(start, end, 1) => write!(f, ",|{}.{}|", start, end),
+            Self::Sequence(start, end, step) => write!(f, ",|{}.{}.{}|", start, end, step),
+            Self::CharRange(start, end) => {
+                write!(f, ",|{:?}.{:?}|", start.to_string(), end.to_string())
+            }
+            Self::Choice => f.write_str("?"),
+            Self::Sample(count) => write!(f, "?{}", count),
+            Self::Shuffle => f.write_str("??"),
+            Self::Wrap(width) => write!(f, "w{}", width),
+            Self::Indent(width) => write!(f, "i{}", width),
+            Self::IndentLiteral(literal) => write!(f, "i{:?}", literal),
+            Self::Dedent => f.write_str("d"),
+            Self::Table(Some(spec)) => write!(f, "t{:?}", spec),
+            Self::Table(None) => f.write_str("t"),
+            Self::Markdown(Some(aligns)) => write!(f, "T{:?}", aligns),
+            Self::Markdown(None) => f.write_str("T"),
+            Self::IsUpper => f.write_str("^"),
+            Self::IsLower => f.write_str("_"),
+            Self::Map(map) => map.fmt(f),
+            Self::Filter(filter) => filter.fmt(f),
+            Self::IsGlob(glob) => write!(f, "g{:?}", glob),
+            Self::FilterGlob(glob) => write!(f, "[g{:?}]", glob),
+            Self::Partition(check) => write!(f, "%{}", check),
+            Self::Group => f.write_str("%="),
+            Self::Select(extremum) => write!(f, ";{}", extremum),
+            Self::SelectIndex(extremum) => write!(f, ";#{}", extremum),
+            Self::Count(check) => write!(f, "#{}", check),
+            Self::CountLiteral(literal) => write!(f, "#{}", literal),
+            Self::Frequency => f.write_str("#"),
+            Self::Find(literal) => write!(f, "|{}|", literal),
+            Self::RFind(literal) => write!(f, "|-{}|", literal),
+            Self::Equals(literal) => write!(f, "=={}", literal),
+            Self::Contains(literal) => write!(f, "=?{}", literal),
+            Self::Dedupe(false) => f.write_str("%%"),
+            Self::Dedupe(true) => f.write_str("%%I"),
+            Self::Similarity(metric) => write!(f, "={}", metric),
+            Self::Diff => f.write_str("=u"),
+            Self::Template(template) => write!(f, "${:?}", template),
+            Self::Codes(radix) => write!(f, "'{}", radix),
+            Self::Chars(radix) => write!(f, "'!{}", radix),
+            Self::Cast(cast) => write!(f, "'{}", cast),
+            Self::MapBlock(block) => write!(f, "{{({})}}", block),
+            Self::FilterBlock(block) => write!(f, "[({})]", block),
+            Self::Fold(block) => write!(f, "&({})", block),
+            Self::Index(idx) => write!(f, "|{}|", idx),
+            Self::Slice(range) => range.fmt(f),
+            Self::Insert(idx) => write!(f, "+|{}|", idx),
+            Self::Remove(idx) => write!(f, "-|{}|", idx),
+            Self::RemoveSlice(range) => write!(f, "-{}", range),
+            Self::Replace(idx) => write!(f, "=|{}|", idx),
+            Self::ReplaceSlice(range) => write!(f, "={}", range),
+        }
+    }
+}
+
+impl<'p> Token<'p> {
+    ///
+    /// Returns the sub-program of this [`Token`] if it has one.
+    ///
+    pub fn block(&self) -> Option<&'p str> {
+        match self {
+            Self::MapBlock(block) | Self::FilterBlock(block) | Self::Fold(block) => Some(block),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Parses a sub-program delimited by `(` and `)`, nested blocks and literals are skipped when
+/// looking for the closing `)`.
+///
+/// ### Returns
+///
+/// * [`Ok`]
+///   * the `input` starts with a closed block, contains the unparsed rest of the string after
+///     the block and the sub-program without the delimiters
+/// * [`Err`]
+///   * the `input` does not start with a `(` or the block is never closed
+///
+fn block(input: &str) -> Result<(&str, &str), NomErr<NomError<&str>>> {
+    let inner = input
+        .strip_prefix('(')
+        .ok_or_else(|| NomErr::Error(NomError::new(input, ErrorKind::Char)))?;
+
+    let mut depth = 0usize;
+    let mut in_literal = false;
+
+    for (idx, ch) in inner.char_indices() {
+        match ch {
+            '"' => in_literal = !in_literal,
+            '(' if !in_literal => depth += 1,
+            ')' if !in_literal => {
+                if depth == 0 {
+                    return Ok((&inner[idx + 1..], &inner[..idx]));
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+
+    Err(NomErr::Error(NomError::new(input, ErrorKind::Char)))
+}
+
+///
+/// Parses a literal delimited by `"`.
+///
+/// ### Returns
+///
+/// * [`Ok`]
+///   * the `input` starts with a closed literal, contains the unparsed rest of the string after
+///     the literal and the literal without the delimiters
+/// * [`Err`]
+///   * the `input` does not start with a `"` or the literal is never closed
+///
+fn literal(input: &str) -> Result<(&str, &str), NomErr<NomError<&str>>> {
+    delimited(tag("\""), take_till(|ch| ch == '"'), tag("\""))(input)
+}
+
+///
+/// Parses a [`Literal`] delimited by `"`, ignoring case if prefixed by `I`.
+///
+/// ### Returns
+///
+/// * [`Ok`]
+///   * the `input` starts with a closed literal, contains the unparsed rest of the string after
+///     the literal and the [`Literal`]
+/// * [`Err`]
+///   * the `input` does not start with a `"` or `I"` or the literal is never closed
+///
+fn case_literal(input: &str) -> Result<(&str, Literal), NomErr<NomError<&str>>> {
+    map(pair(opt(tag("I")), literal), |(ignore_case, text)| {
+        Literal::new(text, ignore_case.is_some())
+    })(input)
+}
+
+///
+/// Finds the next token inside the given `input` string.
+///
+/// ### Returns
+///
+/// * [`Ok`]
+///   * the `input` starts with a valid token, contains the unparsed rest of
+///     the string after the parsed token and the parsed [`Token`]
+/// * [`Err`]
+///   * unable to parse a token, contains the [`Error`]
+///
+pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
+    // ignore whitespace, treat whitespace as empty
+    let trimmed = input.trim_start_matches(|ch: char| ch.is_ascii_whitespace());
+    if trimmed.is_empty() {
+        color_eyre::eyre::bail!("the given program was empty");
+    }
+
+    let tirmlen = input.len() - trimmed.len();
+
+    let mut rest_input = preceded(tag("<:"), alt((tag("s"), tag("l"))));
+
+    let single = (
+        tag("<"),
+        tag(">"),
+        tag("."),
+        tag("~"),
+        tag("*"),
+        tag("d"),
+        tag("v"),
+        tag("c"),
+        tag("_"),
+        tag("^"),
+    );
+
+    let double = (pair(tag("@"), digit0), pair(tag("x"), digit0));
+
+    let int = || recognize(pair(opt(tag("-")), digit1));
+
+    let mut sequence = preceded(
+        tag(","),
+        delimited(
+            tag("|"),
+            tuple((
+                int(),
+                preceded(tag("."), int()),
+                opt(preceded(tag("."), digit1)),
+            )),
+            tag("|"),
+        ),
+    );
+
+    let mut char_range = preceded(
+        tag(","),
+        delimited(
+            tag("|"),
+            separated_pair(literal, tag("."), literal),
+            tag("|"),
+        ),
+    );
+
+    let mut alphabet = preceded(tag(","), alt((tag("a"), tag("A"), tag("d"))));
+
+    let mut random = preceded(tag("?"), alt((tag("?"), digit0)));
+
+    let mut layout = pair(alt((tag("w"), tag("i"))), digit1);
+
+    let mut indent_literal = preceded(tag("i"), literal);
+
+    let mut template = preceded(tag("$"), literal);
+
+    let mut glob = pair(opt(tag("[")), preceded(tag("g"), literal));
+
+    let mut table = pair(alt((tag("t"), tag("T"))), opt(literal));
 
     let mut split = delimited(
         tag("/"),
-        opt(delimited(
-            tag("\""),
-            take_till(|ch| matches!(ch, '"' | '/')),
-            tag("\""),
+        opt(pair(
+            opt(tag("I")),
+            delimited(
+                tag("\""),
+                take_till(|ch| matches!(ch, '"' | '/')),
+                tag("\""),
+            ),
         )),
         tag("/"),
     );
 
     let mut index = delimited(tag("|"), pair(opt(tag("-")), digit1), tag("|"));
 
+    let mut mutate_index = pair(
+        alt((tag("+"), tag("-"), tag("="))),
+        delimited(tag("|"), pair(opt(tag("-")), digit1), tag("|")),
+    );
+
+    let mut mutate_slice = pair(
+        alt((tag("-"), tag("="))),
+        delimited(
+            tag("|"),
+            separated_pair(
+                opt(pair(opt(tag("-")), digit1)),
+                tag("."),
+                opt(pair(opt(tag("-")), digit1)),
+            ),
+            tag("|"),
+        ),
+    );
+
     let mut slice = delimited(
         tag("|"),
         separated_pair(
@@ -240,18 +994,127 @@ pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
         tag("|"),
     );
 
-    let mut filter = delimited(
-        tag("["),
-        alt((tag("v"), tag("c"), tag("_"), tag("^"))),
-        tag("]"),
+    let filter_check = || {
+        delimited(
+            tag("["),
+            alt((tag("v"), tag("c"), tag("_"), tag("^"))),
+            tag("]"),
+        )
+    };
+
+    let mut filter = filter_check();
+
+    let mut map = delimited(
+        tag("{"),
+        alt((
+            tag("_"),
+            tag("^"),
+            tag("%"),
+            tag("t"),
+            tag("f"),
+            tag("s"),
+            tag("S"),
+            tag("k"),
+            tag("c"),
+            tag("p"),
+            tag("r13"),
+            tag("atbash"),
+            tag("nfc"),
+            tag("nfd"),
+            tag("nfkc"),
+            tag("nfkd"),
+            tag("nodia"),
+            tag("ascii"),
+        )),
+        tag("}"),
+    );
+
+    let mut map_trim = delimited(
+        tag("{"),
+        pair(alt((tag("-<"), tag("->"), tag("-"))), opt(literal)),
+        tag("}"),
     );
 
-    let mut map = delimited(tag("{"), alt((tag("_"), tag("^"), tag("%"))), tag("}"));
+    let mut map_pad = delimited(
+        tag("{"),
+        tuple((alt((tag("<"), tag(">"), tag("="))), digit1, opt(literal))),
+        tag("}"),
+    );
+
+    let mut map_truncate = delimited(tag("{"), preceded(tag("."), digit1), tag("}"));
+
+    let mut map_rotate = delimited(tag("{"), preceded(tag("r:"), int()), tag("}"));
+
+    let mut map_named = delimited(
+        tag("{"),
+        pair(
+            opt(tag("!")),
+            alt((
+                tag("b64u"),
+                tag("b64"),
+                tag("hex"),
+                tag("url"),
+                tag("json"),
+                tag("sh"),
+                tag("html"),
+                tag("csv"),
+                tag("re"),
+                tag("rle"),
+            )),
+        ),
+        tag("}"),
+    );
+
+    let radix = || alt((tag("d"), tag("x"), tag("b")));
+
+    let mut map_code = delimited(
+        tag("{"),
+        pair(
+            alt((tag("ord"), tag("chr"))),
+            opt(preceded(tag(":"), radix())),
+        ),
+        tag("}"),
+    );
+
+    let mut codes = preceded(tag("'"), pair(opt(tag("!")), radix()));
+
+    let mut cast = preceded(
+        tag("'"),
+        alt((tag("s"), tag("l"), tag("?"), tag("n"), tag("k"))),
+    );
+
+    let mut select = preceded(
+        tag(";"),
+        pair(opt(tag("#")), alt((tag("<"), tag(">"), tag("-"), tag("+")))),
+    );
+
+    let mut similarity = preceded(tag("="), alt((tag("l"), tag("d"), tag("j"), tag("u"))));
+
+    let mut partition = preceded(tag("%"), filter_check());
+
+    let mut count = preceded(tag("#"), filter_check());
+
+    let mut count_literal = preceded(tag("#"), case_literal);
+
+    let mut find = delimited(tag("|"), pair(opt(tag("-")), case_literal), tag("|"));
+
+    let mut compare = preceded(tag("="), pair(alt((tag("="), tag("?"))), case_literal));
+
+    let mut dedupe = preceded(tag("%%"), opt(tag("I")));
+
+    let mut map_block = delimited(tag("{"), block, tag("}"));
+
+    let mut filter_block = delimited(tag("["), block, tag("]"));
+
+    let mut fold = preceded(tag("&"), block);
 
     type Str<'a> = Result<(&'a str, &'a str), NomErr<NomError<&'a str>>>;
     type Opt<'a> = Result<(&'a str, Option<&'a str>), NomErr<NomError<&'a str>>>;
     type OptStr<'a> = Result<(&'a str, (Option<&'a str>, &'a str)), NomErr<NomError<&'a str>>>;
     type StrStr<'a> = Result<(&'a str, (&'a str, &'a str)), NomErr<NomError<&'a str>>>;
+    type StrOpt<'a> = Result<(&'a str, (&'a str, Option<&'a str>)), NomErr<NomError<&'a str>>>;
+    type StrStrOpt<'a> =
+        Result<(&'a str, (&'a str, &'a str, Option<&'a str>)), NomErr<NomError<&'a str>>>;
 
     // this can surely be done better
     type OptOpt<'a> = Result<
@@ -264,6 +1127,39 @@ pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
         ),
         NomErr<NomError<&'a str>>,
     >;
+    type Lit<'a> = Result<(&'a str, Literal<'a>), NomErr<NomError<&'a str>>>;
+    type OptLit<'a> = Result<(&'a str, (Option<&'a str>, Literal<'a>)), NomErr<NomError<&'a str>>>;
+    type StrLit<'a> = Result<(&'a str, (&'a str, Literal<'a>)), NomErr<NomError<&'a str>>>;
+    type OptOptStr<'a> =
+        Result<(&'a str, Option<(Option<&'a str>, &'a str)>), NomErr<NomError<&'a str>>>;
+    type StrOptStr<'a> =
+        Result<(&'a str, (&'a str, (Option<&'a str>, &'a str))), NomErr<NomError<&'a str>>>;
+    type StrOptOpt<'a> = Result<
+        (
+            &'a str,
+            (
+                &'a str,
+                (
+                    Option<(Option<&'a str>, &'a str)>,
+                    Option<(Option<&'a str>, &'a str)>,
+                ),
+            ),
+        ),
+        NomErr<NomError<&'a str>>,
+    >;
+
+    let rest_input_res: Str = rest_input(trimmed);
+    if let Ok((rest, parsed)) = rest_input_res {
+        return Ok((
+            rest,
+            match parsed {
+                "s" => Token::InString,
+                "l" => Token::InLines,
+                _ => unimplemented!("missing rest input branches"),
+            },
+            tirmlen + 3,
+        ));
+    }
 
     let single_res: Str = alt(single)(trimmed);
     if let Ok((rest, parsed)) = single_res {
@@ -275,6 +1171,7 @@ pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
                 "." => Token::Join,
                 "~" => Token::Concat,
                 "*" => Token::Copy,
+                "d" => Token::Dedent,
                 "v" => Token::IsVowel,
                 "c" => Token::IsConso,
                 "_" => Token::IsLower,
@@ -285,65 +1182,540 @@ pub fn next_token(input: &str) -> color_eyre::Result<(&str, Token, usize)> {
         ));
     }
 
-    let double_res: StrStr = alt(double)(trimmed);
-    if let Ok((rest, (_, second))) = double_res {
+    let double_res: StrStr = alt(double)(trimmed);
+    if let Ok((rest, (first, second))) = double_res {
+        return Ok((
+            rest,
+            match first {
+                "@" => Token::Rotate(second.parse().unwrap_or(1)),
+                "x" => Token::Repeat(if second.is_empty() {
+                    None
+                } else {
+                    Some(second.parse()?)
+                }),
+                _ => unimplemented!("missing double branches"),
+            },
+            tirmlen + 1 + second.len(),
+        ));
+    }
+
+    let sequence_res: StrStrOpt = sequence(trimmed);
+    if let Ok((rest, (start, end, step))) = sequence_res {
+        let step_val = step.map(str::parse).transpose()?.unwrap_or(1);
+        if step_val == 0 {
+            color_eyre::eyre::bail!(format!("sequence step must not be 0 at '{}'", input));
+        }
+
+        return Ok((
+            rest,
+            Token::Sequence(start.parse()?, end.parse()?, step_val),
+            tirmlen
+                + 4
+                + start.len()
+                + end.len()
+                + step.map(|step| step.len() + 1).unwrap_or_default(),
+        ));
+    }
+
+    let char_range_res: StrStr = char_range(trimmed);
+    if let Ok((rest, (start, end))) = char_range_res {
+        let single = |literal: &str| {
+            let mut chars = literal.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Ok(ch),
+                _ => Err(color_eyre::eyre::eyre!(format!(
+                    "char range bounds must be single chars at '{}'",
+                    input
+                ))),
+            }
+        };
+
+        return Ok((
+            rest,
+            Token::CharRange(single(start)?, single(end)?),
+            tirmlen + 8 + start.len() + end.len(),
+        ));
+    }
+
+    let alphabet_res: Str = alphabet(trimmed);
+    if let Ok((rest, parsed)) = alphabet_res {
+        return Ok((
+            rest,
+            match parsed {
+                "a" => Token::CharRange('a', 'z'),
+                "A" => Token::CharRange('A', 'Z'),
+                "d" => Token::CharRange('0', '9'),
+                _ => unimplemented!("missing alphabet branches"),
+            },
+            tirmlen + 2,
+        ));
+    }
+
+    let random_res: Str = random(trimmed);
+    if let Ok((rest, parsed)) = random_res {
+        return Ok((
+            rest,
+            match parsed {
+                "?" => Token::Shuffle,
+                "" => Token::Choice,
+                count => Token::Sample(count.parse()?),
+            },
+            tirmlen + 1 + parsed.len(),
+        ));
+    }
+
+    let layout_res: StrStr = layout(trimmed);
+    if let Ok((rest, (first, second))) = layout_res {
+        return Ok((
+            rest,
+            match first {
+                "w" => Token::Wrap(second.parse()?),
+                "i" => Token::Indent(second.parse()?),
+                _ => unimplemented!("missing layout branches"),
+            },
+            tirmlen + 1 + second.len(),
+        ));
+    }
+
+    let indent_literal_res: Str = indent_literal(trimmed);
+    if let Ok((rest, parsed)) = indent_literal_res {
+        return Ok((
+            rest,
+            Token::IndentLiteral(parsed),
+            tirmlen + 3 + parsed.len(),
+        ));
+    }
+
+    let glob_res: OptStr = glob(trimmed);
+    if let Ok((rest, (bracket, parsed))) = glob_res {
+        // reject invalid patterns before execution
+        Glob::parse(parsed)?;
+
+        if bracket.is_none() {
+            return Ok((rest, Token::IsGlob(parsed), tirmlen + 3 + parsed.len()));
+        }
+
+        let close_res: Str = tag("]")(rest);
+        if let Ok((rest, _)) = close_res {
+            return Ok((rest, Token::FilterGlob(parsed), tirmlen + 5 + parsed.len()));
+        }
+    }
+
+    let template_res: Str = template(trimmed);
+    if let Ok((rest, parsed)) = template_res {
+        // reject invalid placeholders before execution
+        Template::parse(parsed)?;
+
+        return Ok((rest, Token::Template(parsed), tirmlen + 3 + parsed.len()));
+    }
+
+    let table_res: StrOpt = table(trimmed);
+    if let Ok((rest, (first, spec))) = table_res {
+        if first == "T"
+            && !spec
+                .unwrap_or_default()
+                .chars()
+                .all(|ch| "<>=".contains(ch))
+        {
+            color_eyre::eyre::bail!(format!("invalid markdown alignment at '{}'", input));
+        }
+
+        return Ok((
+            rest,
+            match first {
+                "t" => Token::Table(spec),
+                "T" => Token::Markdown(spec),
+                _ => unimplemented!("missing table branches"),
+            },
+            tirmlen + 1 + spec.map(|spec| spec.len() + 2).unwrap_or_default(),
+        ));
+    }
+
+    let mutate_index_res: StrOptStr = mutate_index(trimmed);
+    if let Ok((rest, (op, (sign, num)))) = mutate_index_res {
+        let index = EolinaIndex::from_components(
+            sign.is_some(),
+            num.parse().expect("combinator must not fail"),
+        );
+
+        return Ok((
+            rest,
+            match op {
+                "+" => Token::Insert(index),
+                "-" => Token::Remove(index),
+                "=" => Token::Replace(index),
+                _ => unimplemented!("missing mutate branches"),
+            },
+            tirmlen + 3 + sign.map(str::len).unwrap_or_default() + num.len(),
+        ));
+    }
+
+    let mutate_slice_res: StrOptOpt = mutate_slice(trimmed);
+    if let Ok((rest, (op, (first, second)))) = mutate_slice_res {
+        let parser = |(sign, num): (Option<&str>, &str)| {
+            (
+                sign.is_some(),
+                num.parse().expect("combinator must not fail"),
+            )
+        };
+
+        let counter =
+            |(sign, num): (Option<&str>, &str)| num.len() + if sign.is_some() { 1 } else { 0 };
+
+        let range = EolinaRange::components(first.map(parser), second.map(parser));
+
+        return Ok((
+            rest,
+            match op {
+                "-" => Token::RemoveSlice(range),
+                "=" => Token::ReplaceSlice(range),
+                _ => unimplemented!("missing mutate branches"),
+            },
+            tirmlen
+                + 4
+                + first.map(counter).unwrap_or_default()
+                + second.map(counter).unwrap_or_default(),
+        ));
+    }
+
+    let index_res: OptStr = index(trimmed);
+    if let Ok((rest, (sign, num))) = index_res {
+        return Ok((
+            rest,
+            Token::Index(EolinaIndex::from_components(
+                sign.is_some(),
+                num.parse().expect("combinator must not fail"),
+            )),
+            tirmlen + 2 + num.len(),
+        ));
+    }
+
+    let literal_len = |literal: Literal| literal.text.len() + 2 + usize::from(literal.ignore_case);
+
+    let split_res: OptOptStr = split(trimmed);
+    if let Ok((rest, optional)) = split_res {
+        let optional =
+            optional.map(|(ignore_case, text)| Literal::new(text, ignore_case.is_some()));
+
+        return Ok((
+            rest,
+            Token::Split(optional),
+            tirmlen + 2 + optional.map(literal_len).unwrap_or_default(),
+        ));
+    }
+
+    let map_res: Str = map(trimmed);
+    if let Ok((rest, parsed)) = map_res {
+        return Ok((
+            rest,
+            match parsed {
+                "_" => Token::Map(Map::Lower),
+                "^" => Token::Map(Map::Upper),
+                "%" => Token::Map(Map::Swap),
+                "t" => Token::Map(Map::Title),
+                "f" => Token::Map(Map::Capitalize),
+                "s" => Token::Map(Map::Snake),
+                "S" => Token::Map(Map::Screaming),
+                "k" => Token::Map(Map::Kebab),
+                "c" => Token::Map(Map::Camel),
+                "p" => Token::Map(Map::Pascal),
+                "r13" => Token::Map(Map::Rotate(13)),
+                "atbash" => Token::Map(Map::Atbash),
+                "nfc" => Token::Map(Map::Nfc),
+                "nfd" => Token::Map(Map::Nfd),
+                "nfkc" => Token::Map(Map::Nfkc),
+                "nfkd" => Token::Map(Map::Nfkd),
+                "nodia" => Token::Map(Map::NoDiacritics),
+                "ascii" => Token::Map(Map::Ascii),
+                _ => unimplemented!("missing map branches"),
+            },
+            tirmlen + 2 + parsed.len(),
+        ));
+    }
+
+    let to_radix = |parsed| match parsed {
+        "d" => Radix::Dec,
+        "x" => Radix::Hex,
+        "b" => Radix::Bin,
+        _ => unimplemented!("missing radix branches"),
+    };
+
+    let map_code_res: StrOpt = map_code(trimmed);
+    if let Ok((rest, (parsed, radix))) = map_code_res {
+        let len = radix.map(|_| 2).unwrap_or_default();
+        let radix = radix.map(to_radix).unwrap_or(Radix::Dec);
+
+        return Ok((
+            rest,
+            Token::Map(match parsed {
+                "ord" => Map::Ord(radix),
+                "chr" => Map::Chr(radix),
+                _ => unimplemented!("missing char code branches"),
+            }),
+            tirmlen + 5 + len,
+        ));
+    }
+
+    let map_named_res: OptStr = map_named(trimmed);
+    if let Ok((rest, (inverse, parsed))) = map_named_res {
+        let inverse = inverse.is_some();
+        let map = match parsed {
+            "b64" | "b64u" | "hex" | "url" => {
+                let encoding = match parsed {
+                    "b64" => Encoding::Base64,
+                    "b64u" => Encoding::Base64Url,
+                    "hex" => Encoding::Hex,
+                    "url" => Encoding::Percent,
+                    _ => unreachable!(),
+                };
+
+                if inverse {
+                    Map::Decode(encoding)
+                } else {
+                    Map::Encode(encoding)
+                }
+            }
+            "rle" => {
+                if inverse {
+                    Map::RunLengthDecode
+                } else {
+                    Map::RunLength
+                }
+            }
+            _ => {
+                let format = match parsed {
+                    "json" => Format::Json,
+                    "sh" => Format::Shell,
+                    "html" => Format::Html,
+                    "csv" => Format::Csv,
+                    "re" => Format::Regex,
+                    _ => unimplemented!("missing named map branches"),
+                };
+
+                if inverse {
+                    Map::Unescape(format)
+                } else {
+                    Map::Escape(format)
+                }
+            }
+        };
+
+        return Ok((
+            rest,
+            Token::Map(map),
+            tirmlen + 2 + parsed.len() + if inverse { 1 } else { 0 },
+        ));
+    }
+
+    let map_trim_res: StrOpt = map_trim(trimmed);
+    if let Ok((rest, (side, chars))) = map_trim_res {
+        return Ok((
+            rest,
+            match side {
+                "-" => Token::Map(Map::Trim(chars)),
+                "-<" => Token::Map(Map::TrimStart(chars)),
+                "->" => Token::Map(Map::TrimEnd(chars)),
+                _ => unimplemented!("missing trim branches"),
+            },
+            tirmlen + 2 + side.len() + chars.map(|str| str.len() + 2).unwrap_or_default(),
+        ));
+    }
+
+    let map_pad_res: StrStrOpt = map_pad(trimmed);
+    if let Ok((rest, (side, width, fill))) = map_pad_res {
+        let fill_char = match fill.map(|str| {
+            let mut chars = str.chars();
+            (chars.next(), chars.next())
+        }) {
+            None => ' ',
+            Some((Some(ch), None)) => ch,
+            Some(_) => {
+                color_eyre::eyre::bail!(format!("fill must be a single char at '{}'", input))
+            }
+        };
+
+        let len = tirmlen + 3 + width.len() + fill.map(|str| str.len() + 2).unwrap_or_default();
+        let width = width.parse()?;
+
+        return Ok((
+            rest,
+            match side {
+                "<" => Token::Map(Map::PadLeft(width, fill_char)),
+                ">" => Token::Map(Map::PadRight(width, fill_char)),
+                "=" => Token::Map(Map::PadCenter(width, fill_char)),
+                _ => unimplemented!("missing pad branches"),
+            },
+            len,
+        ));
+    }
+
+    let map_truncate_res: Str = map_truncate(trimmed);
+    if let Ok((rest, width)) = map_truncate_res {
+        return Ok((
+            rest,
+            Token::Map(Map::Truncate(width.parse()?)),
+            tirmlen + 3 + width.len(),
+        ));
+    }
+
+    let map_rotate_res: Str = map_rotate(trimmed);
+    if let Ok((rest, shift)) = map_rotate_res {
+        return Ok((
+            rest,
+            Token::Map(Map::Rotate(shift.parse()?)),
+            tirmlen + 4 + shift.len(),
+        ));
+    }
+
+    let check = |parsed| match parsed {
+        "v" => Check::Vowel,
+        "c" => Check::Conso,
+        "_" => Check::Lower,
+        "^" => Check::Upper,
+        _ => unimplemented!("missing filter branches"),
+    };
+
+    let filter_res: Str = filter(trimmed);
+    if let Ok((rest, parsed)) = filter_res {
+        return Ok((rest, Token::Filter(check(parsed)), tirmlen + 3));
+    }
+
+    let partition_res: Str = partition(trimmed);
+    if let Ok((rest, parsed)) = partition_res {
+        return Ok((rest, Token::Partition(check(parsed)), tirmlen + 4));
+    }
+
+    let select_res: OptStr = select(trimmed);
+    if let Ok((rest, (index, parsed))) = select_res {
+        let extremum = match parsed {
+            "<" => Extremum::Min,
+            ">" => Extremum::Max,
+            "-" => Extremum::Shortest,
+            "+" => Extremum::Longest,
+            _ => unimplemented!("missing extremum branches"),
+        };
+
+        return Ok(if index.is_some() {
+            (rest, Token::SelectIndex(extremum), tirmlen + 3)
+        } else {
+            (rest, Token::Select(extremum), tirmlen + 2)
+        });
+    }
+
+    let group_res: Str = tag("%=")(trimmed);
+    if let Ok((rest, _)) = group_res {
+        return Ok((rest, Token::Group, tirmlen + 2));
+    }
+
+    let count_res: Str = count(trimmed);
+    if let Ok((rest, parsed)) = count_res {
+        return Ok((rest, Token::Count(check(parsed)), tirmlen + 4));
+    }
+
+    let count_literal_res: Lit = count_literal(trimmed);
+    if let Ok((rest, parsed)) = count_literal_res {
         return Ok((
             rest,
-            Token::Rotate(second.parse().unwrap_or(1)),
-            tirmlen + 1 + second.len(),
+            Token::CountLiteral(parsed),
+            tirmlen + 1 + literal_len(parsed),
         ));
     }
 
-    let index_res: OptStr = index(trimmed);
-    if let Ok((rest, (sign, num))) = index_res {
+    let frequency_res: Str = tag("#")(trimmed);
+    if let Ok((rest, _)) = frequency_res {
+        return Ok((rest, Token::Frequency, tirmlen + 1));
+    }
+
+    let find_res: OptLit = find(trimmed);
+    if let Ok((rest, (sign, parsed))) = find_res {
+        return Ok(if sign.is_some() {
+            (
+                rest,
+                Token::RFind(parsed),
+                tirmlen + 3 + literal_len(parsed),
+            )
+        } else {
+            (rest, Token::Find(parsed), tirmlen + 2 + literal_len(parsed))
+        });
+    }
+
+    let compare_res: StrLit = compare(trimmed);
+    if let Ok((rest, (op, parsed))) = compare_res {
         return Ok((
             rest,
-            Token::Index(EolinaIndex::from_components(
-                sign.is_some(),
-                num.parse().expect("combinator must not fail"),
-            )),
-            tirmlen + 2 + num.len(),
+            match op {
+                "=" => Token::Equals(parsed),
+                "?" => Token::Contains(parsed),
+                _ => unimplemented!("missing compare branches"),
+            },
+            tirmlen + 2 + literal_len(parsed),
         ));
     }
 
-    let split_res: Opt = split(trimmed);
-    if let Ok((rest, optional)) = split_res {
+    let dedupe_res: Opt = dedupe(trimmed);
+    if let Ok((rest, ignore_case)) = dedupe_res {
         return Ok((
             rest,
-            Token::Split(optional),
-            tirmlen + optional.map(|str| str.len() + 4).unwrap_or(2),
+            Token::Dedupe(ignore_case.is_some()),
+            tirmlen + 2 + ignore_case.map(str::len).unwrap_or_default(),
         ));
     }
 
-    let map_res: Str = map(trimmed);
-    if let Ok((rest, parsed)) = map_res {
+    let codes_res: OptStr = codes(trimmed);
+    if let Ok((rest, (inverse, parsed))) = codes_res {
+        return Ok(if inverse.is_some() {
+            (rest, Token::Chars(to_radix(parsed)), tirmlen + 3)
+        } else {
+            (rest, Token::Codes(to_radix(parsed)), tirmlen + 2)
+        });
+    }
+
+    let cast_res: Str = cast(trimmed);
+    if let Ok((rest, parsed)) = cast_res {
         return Ok((
             rest,
-            match parsed {
-                "_" => Token::Map(Map::Lower),
-                "^" => Token::Map(Map::Upper),
-                "%" => Token::Map(Map::Swap),
-                _ => unimplemented!("missing map branches"),
-            },
-            tirmlen + 3,
+            Token::Cast(match parsed {
+                "s" => Cast::String,
+                "l" => Cast::List,
+                "?" => Cast::Bool,
+                "n" => Cast::Number,
+                "k" => Cast::Kind,
+                _ => unimplemented!("missing cast branches"),
+            }),
+            tirmlen + 2,
         ));
     }
 
-    let filter_res: Str = filter(trimmed);
-    if let Ok((rest, parsed)) = filter_res {
+    let similarity_res: Str = similarity(trimmed);
+    if let Ok((rest, parsed)) = similarity_res {
         return Ok((
             rest,
             match parsed {
-                "v" => Token::Filter(Check::Vowel),
-                "c" => Token::Filter(Check::Conso),
-                "_" => Token::Filter(Check::Lower),
-                "^" => Token::Filter(Check::Upper),
-                _ => unimplemented!("missing filter branches"),
+                "l" => Token::Similarity(Metric::Levenshtein),
+                "d" => Token::Similarity(Metric::Damerau),
+                "j" => Token::Similarity(Metric::JaroWinkler),
+                "u" => Token::Diff,
+                _ => unimplemented!("missing similarity branches"),
             },
-            tirmlen + 3,
+            tirmlen + 2,
         ));
     }
 
+    let map_block_res: Str = map_block(trimmed);
+    if let Ok((rest, parsed)) = map_block_res {
+        return Ok((rest, Token::MapBlock(parsed), tirmlen + 4 + parsed.len()));
+    }
+
+    let filter_block_res: Str = filter_block(trimmed);
+    if let Ok((rest, parsed)) = filter_block_res {
+        return Ok((rest, Token::FilterBlock(parsed), tirmlen + 4 + parsed.len()));
+    }
+
+    let fold_res: Str = fold(trimmed);
+    if let Ok((rest, parsed)) = fold_res {
+        return Ok((rest, Token::Fold(parsed), tirmlen + 3 + parsed.len()));
+    }
+
     let slice_res: OptOpt = slice(trimmed);
     if let Ok((rest, (first, second))) = slice_res {
         let parser = |(sign, num): (Option<&str>, &str)| {
@@ -379,12 +1751,75 @@ mod test {
     #[test]
     fn single() {
         assert_eq!(next_token("<").unwrap(), ("", Token::In, 1));
+        assert_eq!(next_token("<:s>").unwrap(), (">", Token::InString, 3));
+        assert_eq!(next_token("<:l").unwrap(), ("", Token::InLines, 3));
+        assert_eq!(next_token("<<:l").unwrap(), ("<:l", Token::In, 1));
+        assert_eq!(next_token("<.>").unwrap(), (".>", Token::In, 1));
+        assert_eq!(next_token("<:").unwrap(), (":", Token::In, 1));
         assert_eq!(next_token(">").unwrap(), ("", Token::Out, 1));
         assert_eq!(next_token(".").unwrap(), ("", Token::Join, 1));
         assert_eq!(next_token("v").unwrap(), ("", Token::IsVowel, 1));
         assert_eq!(next_token("c").unwrap(), ("", Token::IsConso, 1));
         assert_eq!(next_token("_").unwrap(), ("", Token::IsLower, 1));
         assert_eq!(next_token("^").unwrap(), ("", Token::IsUpper, 1));
+        assert_eq!(next_token("d").unwrap(), ("", Token::Dedent, 1));
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(
+            next_token(",|0.10|").unwrap(),
+            ("", Token::Sequence(0, 10, 1), 7)
+        );
+        assert_eq!(
+            next_token(",|-3.-10.2|").unwrap(),
+            ("", Token::Sequence(-3, -10, 2), 11)
+        );
+        assert_eq!(
+            next_token(",|\"a\".\"f\"|").unwrap(),
+            ("", Token::CharRange('a', 'f'), 10)
+        );
+        assert_eq!(
+            next_token(",A").unwrap(),
+            ("", Token::CharRange('A', 'Z'), 2)
+        );
+        assert_eq!(
+            next_token(",d").unwrap(),
+            ("", Token::CharRange('0', '9'), 2)
+        );
+        assert!(next_token(",|0.10.0|").is_err());
+        assert!(next_token(",|.10|").is_err());
+        assert!(next_token(",|\"ab\".\"f\"|").is_err());
+    }
+
+    #[test]
+    fn random() {
+        assert_eq!(next_token("?").unwrap(), ("", Token::Choice, 1));
+        assert_eq!(next_token("?3").unwrap(), ("", Token::Sample(3), 2));
+        assert_eq!(next_token("??").unwrap(), ("", Token::Shuffle, 2));
+        assert_eq!(next_token("???").unwrap(), ("?", Token::Shuffle, 2));
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(next_token("w80").unwrap(), ("", Token::Wrap(80), 3));
+        assert_eq!(next_token("i4").unwrap(), ("", Token::Indent(4), 2));
+        assert_eq!(
+            next_token("i\"> \"").unwrap(),
+            ("", Token::IndentLiteral("> "), 5)
+        );
+        assert_eq!(next_token("t").unwrap(), ("", Token::Table(None), 1));
+        assert_eq!(
+            next_token("t\"<> | \"").unwrap(),
+            ("", Token::Table(Some("<> | ")), 8)
+        );
+        assert_eq!(
+            next_token("T\"=>\"").unwrap(),
+            ("", Token::Markdown(Some("=>")), 5)
+        );
+        assert!(next_token("T\"<|\"").is_err());
+        assert!(next_token("w").is_err());
+        assert!(next_token("i").is_err());
     }
 
     #[test]
@@ -392,11 +1827,15 @@ mod test {
         assert_eq!(next_token("//").unwrap(), ("", Token::Split(None), 2));
         assert_eq!(
             next_token("/\"\"/").unwrap(),
-            ("", Token::Split(Some("")), 4)
+            ("", Token::Split(Some("".into())), 4)
         );
         assert_eq!(
             next_token("/\"aa\"/").unwrap(),
-            ("", Token::Split(Some("aa")), 6)
+            ("", Token::Split(Some("aa".into())), 6)
+        );
+        assert_eq!(
+            next_token("/I\"aA\"/").unwrap(),
+            ("", Token::Split(Some(Literal::new("aA", true))), 7)
         );
     }
 
@@ -405,6 +1844,9 @@ mod test {
         assert_eq!(next_token("@").unwrap(), ("", Token::Rotate(1), 1));
         assert_eq!(next_token("@1").unwrap(), ("", Token::Rotate(1), 2));
         assert_eq!(next_token("@3").unwrap(), ("", Token::Rotate(3), 2));
+        assert_eq!(next_token("x").unwrap(), ("", Token::Repeat(None), 1));
+        assert_eq!(next_token("x12").unwrap(), ("", Token::Repeat(Some(12)), 3));
+        assert!(next_token("x99999999999999999999999").is_err());
     }
 
     #[test]
@@ -412,6 +1854,238 @@ mod test {
         assert_eq!(next_token("{_}").unwrap(), ("", Token::Map(Map::Lower), 3));
         assert_eq!(next_token("{^}").unwrap(), ("", Token::Map(Map::Upper), 3));
         assert_eq!(next_token("{%}").unwrap(), ("", Token::Map(Map::Swap), 3));
+        assert_eq!(next_token("{t}").unwrap(), ("", Token::Map(Map::Title), 3));
+        assert_eq!(
+            next_token("{f}").unwrap(),
+            ("", Token::Map(Map::Capitalize), 3)
+        );
+        assert_eq!(next_token("{s}").unwrap(), ("", Token::Map(Map::Snake), 3));
+        assert_eq!(
+            next_token("{S}").unwrap(),
+            ("", Token::Map(Map::Screaming), 3)
+        );
+        assert_eq!(next_token("{k}").unwrap(), ("", Token::Map(Map::Kebab), 3));
+        assert_eq!(next_token("{c}").unwrap(), ("", Token::Map(Map::Camel), 3));
+        assert_eq!(next_token("{p}").unwrap(), ("", Token::Map(Map::Pascal), 3));
+        assert_eq!(
+            next_token("{atbash}").unwrap(),
+            ("", Token::Map(Map::Atbash), 8)
+        );
+        assert_eq!(next_token("{nfc}").unwrap(), ("", Token::Map(Map::Nfc), 5));
+        assert_eq!(
+            next_token("{nfkd}").unwrap(),
+            ("", Token::Map(Map::Nfkd), 6)
+        );
+        assert_eq!(
+            next_token("{nodia}").unwrap(),
+            ("", Token::Map(Map::NoDiacritics), 7)
+        );
+        assert_eq!(
+            next_token("{ascii}").unwrap(),
+            ("", Token::Map(Map::Ascii), 7)
+        );
+    }
+
+    #[test]
+    fn map_rotate() {
+        assert_eq!(
+            next_token("{r13}").unwrap(),
+            ("", Token::Map(Map::Rotate(13)), 5)
+        );
+        assert_eq!(
+            next_token("{r:3}").unwrap(),
+            ("", Token::Map(Map::Rotate(3)), 5)
+        );
+        assert_eq!(
+            next_token("{r:-25}").unwrap(),
+            ("", Token::Map(Map::Rotate(-25)), 7)
+        );
+        assert_eq!(
+            next_token("{r:-9223372036854775808}").unwrap(),
+            ("", Token::Map(Map::Rotate(isize::MIN)), 24)
+        );
+        assert!(next_token("{r:}").is_err());
+    }
+
+    #[test]
+    fn map_encoding() {
+        assert_eq!(
+            next_token("{b64}").unwrap(),
+            ("", Token::Map(Map::Encode(Encoding::Base64)), 5)
+        );
+        assert_eq!(
+            next_token("{!b64u}").unwrap(),
+            ("", Token::Map(Map::Decode(Encoding::Base64Url)), 7)
+        );
+        assert_eq!(
+            next_token("{hex}").unwrap(),
+            ("", Token::Map(Map::Encode(Encoding::Hex)), 5)
+        );
+        assert_eq!(
+            next_token("{!url}").unwrap(),
+            ("", Token::Map(Map::Decode(Encoding::Percent)), 6)
+        );
+        assert!(next_token("{!}").is_err());
+    }
+
+    #[test]
+    fn map_run_length() {
+        assert_eq!(
+            next_token("{rle}").unwrap(),
+            ("", Token::Map(Map::RunLength), 5)
+        );
+        assert_eq!(
+            next_token("{!rle}").unwrap(),
+            ("", Token::Map(Map::RunLengthDecode), 6)
+        );
+        assert_eq!(
+            next_token("{re}").unwrap(),
+            ("", Token::Map(Map::Escape(Format::Regex)), 4)
+        );
+    }
+
+    #[test]
+    fn map_escape() {
+        assert_eq!(
+            next_token("{json}").unwrap(),
+            ("", Token::Map(Map::Escape(Format::Json)), 6)
+        );
+        assert_eq!(
+            next_token("{!sh}").unwrap(),
+            ("", Token::Map(Map::Unescape(Format::Shell)), 5)
+        );
+        assert_eq!(
+            next_token("{html}").unwrap(),
+            ("", Token::Map(Map::Escape(Format::Html)), 6)
+        );
+        assert_eq!(
+            next_token("{!csv}").unwrap(),
+            ("", Token::Map(Map::Unescape(Format::Csv)), 6)
+        );
+        assert_eq!(
+            next_token("{re}").unwrap(),
+            ("", Token::Map(Map::Escape(Format::Regex)), 4)
+        );
+    }
+
+    #[test]
+    fn map_code() {
+        assert_eq!(
+            next_token("{ord}").unwrap(),
+            ("", Token::Map(Map::Ord(Radix::Dec)), 5)
+        );
+        assert_eq!(
+            next_token("{ord:x}").unwrap(),
+            ("", Token::Map(Map::Ord(Radix::Hex)), 7)
+        );
+        assert_eq!(
+            next_token("{chr:b}").unwrap(),
+            ("", Token::Map(Map::Chr(Radix::Bin)), 7)
+        );
+        assert!(next_token("{chr:o}").is_err());
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(next_token("'d").unwrap(), ("", Token::Codes(Radix::Dec), 2));
+        assert_eq!(
+            next_token("'!x").unwrap(),
+            ("", Token::Chars(Radix::Hex), 3)
+        );
+        assert!(next_token("'").is_err());
+        assert!(next_token("'!o").is_err());
+    }
+
+    #[test]
+    fn similarity() {
+        assert_eq!(
+            next_token("=l").unwrap(),
+            ("", Token::Similarity(Metric::Levenshtein), 2)
+        );
+        assert_eq!(
+            next_token("=d").unwrap(),
+            ("", Token::Similarity(Metric::Damerau), 2)
+        );
+        assert_eq!(
+            next_token("=j").unwrap(),
+            ("", Token::Similarity(Metric::JaroWinkler), 2)
+        );
+        assert_eq!(next_token("=u").unwrap(), ("", Token::Diff, 2));
+    }
+
+    #[test]
+    fn cast() {
+        assert_eq!(
+            next_token("'s").unwrap(),
+            ("", Token::Cast(Cast::String), 2)
+        );
+        assert_eq!(next_token("'?").unwrap(), ("", Token::Cast(Cast::Bool), 2));
+        assert_eq!(next_token(" 'k").unwrap(), ("", Token::Cast(Cast::Kind), 3));
+        assert_eq!(next_token("'x").unwrap(), ("", Token::Codes(Radix::Hex), 2));
+    }
+
+    #[test]
+    fn glob() {
+        assert_eq!(
+            next_token("g\"*.rs\"").unwrap(),
+            ("", Token::IsGlob("*.rs"), 7)
+        );
+        assert_eq!(
+            next_token("[g\"[ab]?\"]>").unwrap(),
+            (">", Token::FilterGlob("[ab]?"), 10)
+        );
+        assert!(next_token("g\"[a\"").is_err());
+        assert!(next_token("[g\"a\"").is_err());
+    }
+
+    #[test]
+    fn template() {
+        assert_eq!(
+            next_token("$\"{} {1:,}\"").unwrap(),
+            ("", Token::Template("{} {1:,}"), 11)
+        );
+        assert_eq!(next_token("$\"\"").unwrap(), ("", Token::Template(""), 3));
+        assert!(next_token("$\"{\"").is_err());
+        assert!(next_token("$\"{x}\"").is_err());
+        assert!(next_token("=x").is_err());
+    }
+
+    #[test]
+    fn map_trim() {
+        assert_eq!(
+            next_token("{-}").unwrap(),
+            ("", Token::Map(Map::Trim(None)), 3)
+        );
+        assert_eq!(
+            next_token("{-<}").unwrap(),
+            ("", Token::Map(Map::TrimStart(None)), 4)
+        );
+        assert_eq!(
+            next_token("{->\"xy\"}").unwrap(),
+            ("", Token::Map(Map::TrimEnd(Some("xy"))), 8)
+        );
+    }
+
+    #[test]
+    fn map_pad() {
+        assert_eq!(
+            next_token("{<8}").unwrap(),
+            ("", Token::Map(Map::PadLeft(8, ' ')), 4)
+        );
+        assert_eq!(
+            next_token("{>12\".\"}").unwrap(),
+            ("", Token::Map(Map::PadRight(12, '.')), 8)
+        );
+        assert_eq!(
+            next_token("{=3\"0\"}").unwrap(),
+            ("", Token::Map(Map::PadCenter(3, '0')), 7)
+        );
+        assert_eq!(
+            next_token("{.10}").unwrap(),
+            ("", Token::Map(Map::Truncate(10)), 5)
+        );
+        assert!(next_token("{<8\"ab\"}").is_err());
+        assert!(next_token("{<}").is_err());
     }
 
     #[test]
@@ -434,6 +2108,116 @@ mod test {
         );
     }
 
+    #[test]
+    fn partition() {
+        assert_eq!(
+            next_token("%[v]").unwrap(),
+            ("", Token::Partition(Check::Vowel), 4)
+        );
+        assert_eq!(next_token("%=").unwrap(), ("", Token::Group, 2));
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(
+            next_token(";<").unwrap(),
+            ("", Token::Select(Extremum::Min), 2)
+        );
+        assert_eq!(
+            next_token(";+").unwrap(),
+            ("", Token::Select(Extremum::Longest), 2)
+        );
+        assert_eq!(
+            next_token(";#-").unwrap(),
+            ("", Token::SelectIndex(Extremum::Shortest), 3)
+        );
+        assert!(next_token(";#").is_err());
+        assert_eq!(
+            next_token("%[^]>").unwrap(),
+            (">", Token::Partition(Check::Upper), 4)
+        );
+        assert!(next_token("%").is_err());
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(
+            next_token("#[c]").unwrap(),
+            ("", Token::Count(Check::Conso), 4)
+        );
+        assert_eq!(
+            next_token("#\"ab\"").unwrap(),
+            ("", Token::CountLiteral("ab".into()), 5)
+        );
+        assert_eq!(
+            next_token("#I\"ab\"").unwrap(),
+            ("", Token::CountLiteral(Literal::new("ab", true)), 6)
+        );
+        assert_eq!(
+            next_token("#i\"> \"").unwrap(),
+            ("i\"> \"", Token::Frequency, 1)
+        );
+        assert_eq!(next_token("#>").unwrap(), (">", Token::Frequency, 1));
+        assert_eq!(next_token("#\"ab").unwrap(), ("\"ab", Token::Frequency, 1));
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(
+            next_token("|\"ab\"|").unwrap(),
+            ("", Token::Find("ab".into()), 6)
+        );
+        assert_eq!(
+            next_token("|-\"a|b\"|").unwrap(),
+            ("", Token::RFind("a|b".into()), 8)
+        );
+        assert_eq!(
+            next_token("|-I\"a\"|").unwrap(),
+            ("", Token::RFind(Literal::new("a", true)), 7)
+        );
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(
+            next_token("==\"ab\"").unwrap(),
+            ("", Token::Equals("ab".into()), 6)
+        );
+        assert_eq!(
+            next_token("=?I\"ab\"").unwrap(),
+            ("", Token::Contains(Literal::new("ab", true)), 7)
+        );
+        assert_eq!(next_token("%%").unwrap(), ("", Token::Dedupe(false), 2));
+        assert_eq!(next_token("%%I").unwrap(), ("", Token::Dedupe(true), 3));
+        assert_eq!(next_token("%%i2").unwrap(), ("i2", Token::Dedupe(false), 2));
+        assert!(next_token("==I").is_err());
+        assert!(next_token("|\"ab|").is_err());
+    }
+
+    #[test]
+    fn block() {
+        assert_eq!(
+            next_token("{(*~)}").unwrap(),
+            ("", Token::MapBlock("*~"), 6)
+        );
+        assert_eq!(
+            next_token("[(v)]").unwrap(),
+            ("", Token::FilterBlock("v"), 5)
+        );
+        assert_eq!(
+            next_token("{(//[(c)].)}>").unwrap(),
+            (">", Token::MapBlock("//[(c)]."), 12)
+        );
+        assert_eq!(
+            next_token("{(/\")\"/.)}").unwrap(),
+            ("", Token::MapBlock("/\")\"/."), 10)
+        );
+        assert_eq!(next_token("&(~)").unwrap(), ("", Token::Fold("~"), 4));
+        assert!(next_token("{(*~}").is_err());
+        assert!(next_token("[(v]").is_err());
+        assert!(next_token("&(~").is_err());
+    }
+
     #[test]
     fn index() {
         assert_eq!(
@@ -466,6 +2250,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn mutate() {
+        assert_eq!(
+            next_token("+|-0|").unwrap(),
+            ("", Token::Insert(EolinaIndex::End(0)), 5)
+        );
+        assert_eq!(
+            next_token("-|3|").unwrap(),
+            ("", Token::Remove(3usize.into()), 4)
+        );
+        assert_eq!(
+            next_token("-|1.-1|").unwrap(),
+            ("", Token::RemoveSlice((1..-1isize).into()), 7)
+        );
+        assert_eq!(
+            next_token("=|.|").unwrap(),
+            ("", Token::ReplaceSlice((..).into()), 4)
+        );
+        assert_eq!(
+            next_token("=|-12|").unwrap(),
+            ("", Token::Replace((-12isize).into()), 6)
+        );
+        assert!(next_token("+|.|").is_err());
+    }
+
     #[test]
     fn repeating() {
         assert_eq!(next_token("<>//|.|").unwrap(), (">//|.|", Token::In, 1));
@@ -1,17 +1,23 @@
-use super::{func, Error, Value};
+use super::{func, layout, Error, Value};
 use crate::{
     cli,
-    parse::{Gen, Token},
+    helper::{Glob, Rng, Template},
+    parse::{Gen, LazyGen, Token},
 };
 use crossterm::style::Stylize;
 use std::{
     collections::VecDeque,
-    io::{self, Write},
+    io::{self, Read, Write},
     mem::MaybeUninit,
     ops::{Generator, GeneratorState},
     pin::Pin,
 };
 
+///
+/// The default maximum size in bytes of values created by repeating.
+///
+pub const DEFAULT_MAX_SIZE: usize = 16 * 1024 * 1024;
+
 ///
 /// The excution context for a program.
 ///
@@ -51,6 +57,21 @@ pub struct Context<'p, 'v, G> {
     /// Whether or not this context is used in a repl.
     ///
     is_repl: bool,
+
+    ///
+    /// Whether or not this context executes a sub-program, which may not read input.
+    ///
+    is_block: bool,
+
+    ///
+    /// The maximum size in bytes of values created by repeating.
+    ///
+    max_size: usize,
+
+    ///
+    /// The random number generator, child contexts receive their own seeded from it.
+    ///
+    rng: Rng,
 }
 
 impl<'p, 'v, G> Context<'p, 'v, G> {
@@ -63,6 +84,8 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
         args: Option<Vec<String>>,
         values: &'v mut VecDeque<Value>,
         is_repl: bool,
+        max_size: usize,
+        rng: Rng,
     ) -> Self {
         Self {
             token_start: 0,
@@ -72,6 +95,9 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
             args,
             values,
             is_repl,
+            is_block: false,
+            max_size,
+            rng,
         }
     }
 
@@ -120,6 +146,59 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
         self.log_queue();
     }
 
+    ///
+    /// Pops `count` values off the front of the queue or returns a [`Error::QueueTooShort`]
+    /// error, like [`Self::pop_queue`] for counts only known at runtime.
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the queue was long enough, contains the [`Value`]s in the order they
+    ///     were popped off
+    /// * [`Err`]
+    ///   * the queue was too short, contains the [`Error`]
+    ///
+    fn pop_queue_dynamic(&mut self, count: usize) -> Result<Vec<Value>, Error> {
+        if self.values.len() < count {
+            Err(Error::QueueTooShort(count, self.values.len()))
+        } else {
+            Ok(self.values.drain(..count).collect())
+        }
+    }
+
+    ///
+    /// Prints the input prompt if this context is used in a repl.
+    ///
+    fn prompt(&self) -> io::Result<()> {
+        if self.is_repl {
+            if *cli::IS_FANCY {
+                eprint!("[{}] [{}]: ", "inp".green(), self.get_context())
+            } else {
+                eprint!("[inp] [{}]: ", self.get_context())
+            }
+            io::stdout().flush()?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Reads all remaining input, the remaining command line args joined by newlines if any are
+    /// left, otherwise stdin until it's end.
+    ///
+    fn read_rest(&mut self) -> io::Result<String> {
+        self.prompt()?;
+
+        match self.args.as_mut().filter(|vec| !vec.is_empty()) {
+            // args are popped from the back
+            Some(vec) => Ok(vec.drain(..).rev().collect::<Vec<_>>().join("\n")),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
     ///
     /// Logs the current queue as debug.
     ///
@@ -150,6 +229,40 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
         }
     }
 
+    ///
+    /// Executes the given sub-program `block` to completion in a child [`Context`] with a fresh
+    /// queue containing only the given `values`.
+    ///
+    /// ### Returns
+    ///
+    /// * [`Ok`]
+    ///   * the sub-program was successfully executed, contains the front of its queue
+    /// * [`Err`]
+    ///   * the sub-program was not successfully executed
+    ///   * the sub-program tried to read input
+    ///   * the sub-program left an empty queue
+    ///
+    fn exec_block<const N: usize>(
+        &self,
+        block: &'p str,
+        values: [Value; N],
+    ) -> color_eyre::Result<Value> {
+        let mut queue = VecDeque::from(values);
+        let mut context = Context::new(
+            block,
+            LazyGen::new(block),
+            None,
+            &mut queue,
+            self.is_repl,
+            self.max_size,
+            Rng::new(self.rng.next_u64()),
+        );
+        context.is_block = true;
+        context.run()?;
+
+        Ok(queue.pop_front().ok_or(Error::QueueTooShort(1, 0))?)
+    }
+
     ///
     /// Executes the function associated with the given [`Token`].
     ///
@@ -163,24 +276,15 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
     ///
     fn exec_token(&mut self, token: Token) -> color_eyre::Result<()> {
         match token {
+            Token::In | Token::InString | Token::InLines if self.is_block => {
+                return Err(Error::InputInBlock.into());
+            }
             Token::In => {
-                let prompt = |this: &mut Self| -> io::Result<()> {
-                    if this.is_repl {
-                        if *cli::IS_FANCY {
-                            eprint!("[{}] [{}]: ", "inp".green(), this.get_context())
-                        } else {
-                            eprint!("[inp] [{}]: ", this.get_context())
-                        }
-                        io::stdout().flush()?;
-                    }
-                    Ok(())
-                };
-
                 let val = if let Some(value) = self.args.as_mut().and_then(|vec| vec.pop()) {
-                    prompt(self)?;
+                    self.prompt()?;
                     value
                 } else {
-                    prompt(self)?;
+                    self.prompt()?;
 
                     let mut input = String::new();
                     io::stdin().read_line(&mut input)?;
@@ -195,6 +299,14 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
 
                 self.push_queue([Value::String(val)]);
             }
+            Token::InString => {
+                let rest = self.read_rest()?;
+                self.push_queue([func::read_string(&rest)]);
+            }
+            Token::InLines => {
+                let rest = self.read_rest()?;
+                self.push_queue([func::read_lines(&rest)]);
+            }
             Token::Out => {
                 let [val] = self.pop_queue()?;
                 if self.is_repl {
@@ -211,6 +323,69 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
                 self.values.rotate_left(num);
                 self.log_queue();
             }
+            Token::Repeat(Some(count)) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::repeat(val, count, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Repeat(None) => {
+                let [val, count] = self.pop_queue()?;
+                let ret = func::repeat_by(val, count, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Sequence(start, end, step) => {
+                let ret = func::sequence(start, end, step, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::CharRange(start, end) => {
+                let ret = func::char_range(start, end, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Choice => {
+                let [val] = self.pop_queue()?;
+                let ret = func::choice(val, &self.rng)?;
+                self.push_queue([ret]);
+            }
+            Token::Sample(count) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::sample(val, count, &self.rng)?;
+                self.push_queue([ret]);
+            }
+            Token::Shuffle => {
+                let [val] = self.pop_queue()?;
+                let ret = func::shuffle(val, &self.rng)?;
+                self.push_queue([ret]);
+            }
+            Token::Wrap(width) => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::wrap(val, width)?;
+                self.push_queue([ret]);
+            }
+            Token::Indent(width) => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::indent(val, " ", width, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::IndentLiteral(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::indent(val, literal, 1, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Table(spec) => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::table(val, spec.unwrap_or_default())?;
+                self.push_queue([ret]);
+            }
+            Token::Markdown(aligns) => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::markdown(val, aligns.unwrap_or_default())?;
+                self.push_queue([ret]);
+            }
+            Token::Dedent => {
+                let [val] = self.pop_queue()?;
+                let ret = layout::dedent(val)?;
+                self.push_queue([ret]);
+            }
             Token::Split(split) => {
                 let [val] = self.pop_queue()?;
                 let ret = func::split(val, split)?;
@@ -252,7 +427,7 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
             }
             Token::Map(map) => {
                 let [val] = self.pop_queue()?;
-                let ret = func::map(val, map)?;
+                let ret = func::map(val, map, self.max_size)?;
                 self.push_queue([ret]);
             }
             Token::Filter(filter) => {
@@ -260,6 +435,123 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
                 let ret = func::filter(val, filter)?;
                 self.push_queue([ret]);
             }
+            Token::IsGlob(glob) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::is_glob(val, &Glob::parse(glob)?)?;
+                self.push_queue([ret]);
+            }
+            Token::FilterGlob(glob) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::filter_glob(val, &Glob::parse(glob)?)?;
+                self.push_queue([ret]);
+            }
+            Token::Partition(check) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::partition(val, check)?;
+                self.push_queue(ret);
+            }
+            Token::Count(check) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::count(val, check)?;
+                self.push_queue([ret]);
+            }
+            Token::CountLiteral(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::count_literal(val, literal)?;
+                self.push_queue([ret]);
+            }
+            Token::Select(extremum) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::select(val, extremum)?;
+                self.push_queue([ret]);
+            }
+            Token::SelectIndex(extremum) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::select_index(val, extremum)?;
+                self.push_queue([ret]);
+            }
+            Token::Group => {
+                let [val] = self.pop_queue()?;
+                let ret = func::group(val)?;
+                self.push_queue([ret]);
+            }
+            Token::Frequency => {
+                let [val] = self.pop_queue()?;
+                let ret = func::frequency(val)?;
+                self.push_queue([ret]);
+            }
+            Token::Find(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::find(val, literal)?;
+                self.push_queue([ret]);
+            }
+            Token::RFind(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::rfind(val, literal)?;
+                self.push_queue([ret]);
+            }
+            Token::Equals(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::equals(val, literal)?;
+                self.push_queue([ret]);
+            }
+            Token::Contains(literal) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::contains(val, literal)?;
+                self.push_queue([ret]);
+            }
+            Token::Dedupe(ignore_case) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::dedupe(val, ignore_case)?;
+                self.push_queue([ret]);
+            }
+            Token::Similarity(metric) => {
+                let [val1, val2] = self.pop_queue()?;
+                let ret = func::similarity(val1, val2, metric, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Template(template) => {
+                let template = Template::parse(template)?;
+                let vals = self.pop_queue_dynamic(template.arity())?;
+                let ret = func::template(&template, &vals);
+                self.push_queue([ret]);
+            }
+            Token::Cast(kind) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::cast(val, kind)?;
+                self.push_queue([ret]);
+            }
+            Token::Diff => {
+                let [val1, val2] = self.pop_queue()?;
+                let ret = func::diff(val1, val2, self.max_size)?;
+                self.push_queue([ret]);
+            }
+            Token::Codes(radix) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::codes(val, radix)?;
+                self.push_queue([ret]);
+            }
+            Token::Chars(radix) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::chars(val, radix)?;
+                self.push_queue([ret]);
+            }
+            Token::MapBlock(block) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::map_with(val, |val| self.exec_block(block, [val]))?;
+                self.push_queue([ret]);
+            }
+            Token::FilterBlock(block) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::filter_with(val, |val| self.exec_block(block, [val]))?;
+                self.push_queue([ret]);
+            }
+            Token::Fold(block) => {
+                let [val, init] = self.pop_queue()?;
+                let ret =
+                    func::fold_with(val, init, |acc, val| self.exec_block(block, [acc, val]))?;
+                self.push_queue([ret]);
+            }
             Token::Index(idx) => {
                 let [val] = self.pop_queue()?;
                 let ret = func::index(val, idx)?;
@@ -270,6 +562,31 @@ impl<'p, 'v, G> Context<'p, 'v, G> {
                 let ret = func::slice(val, range)?;
                 self.push_queue([ret]);
             }
+            Token::Insert(idx) => {
+                let [val1, val2] = self.pop_queue()?;
+                let ret = func::insert(val1, val2, idx)?;
+                self.push_queue([ret]);
+            }
+            Token::Remove(idx) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::remove(val, idx)?;
+                self.push_queue([ret]);
+            }
+            Token::RemoveSlice(range) => {
+                let [val] = self.pop_queue()?;
+                let ret = func::remove_slice(val, range)?;
+                self.push_queue([ret]);
+            }
+            Token::Replace(idx) => {
+                let [val1, val2] = self.pop_queue()?;
+                let ret = func::replace(val1, val2, idx)?;
+                self.push_queue([ret]);
+            }
+            Token::ReplaceSlice(range) => {
+                let [val1, val2] = self.pop_queue()?;
+                let ret = func::replace_slice(val1, val2, range)?;
+                self.push_queue([ret]);
+            }
         }
         Ok(())
     }
@@ -338,3 +655,58 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(program: &str, args: Vec<String>) -> color_eyre::Result<VecDeque<Value>> {
+        let mut queue = VecDeque::new();
+        Context::new(
+            program,
+            LazyGen::new(program),
+            Some(args),
+            &mut queue,
+            false,
+            DEFAULT_MAX_SIZE,
+            Rng::new(0),
+        )
+        .run()?;
+
+        Ok(queue)
+    }
+
+    #[test]
+    fn input_in_block() {
+        for program in ["<{(<~)}", "<[(<:s)]", "<<&(<:l)"] {
+            let err = run(program, vec!["b".to_owned(), "a".to_owned()]).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<Error>(),
+                Some(Error::InputInBlock)
+            ));
+        }
+
+        assert_eq!(
+            run("<{(*~)}<", vec!["b".to_owned(), "a".to_owned()]).unwrap(),
+            [
+                Value::String("aa".to_owned()),
+                Value::String("b".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn seeded() {
+        let args = || vec!["abcdefgh ijklmnop".to_owned()];
+        let shuffled = run("</\" \"/{(//??.)}", args()).unwrap();
+
+        assert_eq!(run("</\" \"/{(//??.)}", args()).unwrap(), shuffled);
+        assert_ne!(
+            shuffled,
+            [Value::StringVec(vec![
+                "abcdefgh".to_owned(),
+                "ijklmnop".to_owned()
+            ])]
+        );
+    }
+}
@@ -18,18 +18,96 @@ pub enum Error {
     #[error("range error")]
     Range(#[from] crate::helper::RangeError),
 
+    ///
+    /// An encoded input could not be decoded.
+    ///
+    #[error("failed to decode input")]
+    Decode(#[from] crate::helper::DecodeError),
+
+    ///
+    /// An escaped input could not be unescaped.
+    ///
+    #[error("failed to unescape input")]
+    Unescape(#[from] crate::helper::UnescapeError),
+
+    ///
+    /// A template contained an invalid placeholder.
+    ///
+    #[error("failed to parse template")]
+    Template(#[from] crate::helper::TemplateError),
+
+    ///
+    /// A glob pattern was invalid.
+    ///
+    #[error("failed to parse glob")]
+    Glob(#[from] crate::helper::GlobError),
+
     ///
     /// A function argument was not of an expected type.
     ///
     #[error("arg mismatch")]
     ArgMismatch(#[from] ArgMismatchError),
 
+    ///
+    /// A value was expected to be a single char.
+    ///
+    #[error("expected a single char, found {0:?}")]
+    NotChar(String),
+
+    ///
+    /// A byte index into a [`String`] was not on a char boundary.
+    ///
+    #[error("byte index {0} is not a char boundary")]
+    NotCharBoundary(usize),
+
+    ///
+    /// A value was not a valid char code.
+    ///
+    #[error("invalid char code {0:?}")]
+    CharCode(String),
+
+    ///
+    /// A value was expected to be non-empty.
+    ///
+    #[error("expected a non-empty {0}")]
+    Empty(Kind),
+
+    ///
+    /// A value was expected to be a number.
+    ///
+    #[error("expected a number, found {0:?}")]
+    NotNumber(String),
+
+    ///
+    /// A value was not a valid run-length encoding.
+    ///
+    #[error("invalid run-length encoding {0:?}")]
+    RunLength(String),
+
+    ///
+    /// A value was expected to be a count.
+    ///
+    #[error("expected a count, found {0:?}")]
+    NotCount(String),
+
+    ///
+    /// A value would have exceeded the maximum size in bytes.
+    ///
+    #[error("output of at least {0} bytes exceeds the maximum size of {1} bytes")]
+    TooLarge(usize, usize),
+
     ///
     /// Two values were not of the same type.
     ///
     #[error("type mismatch: <{0}> != <{1}>")]
     Mismatch(Kind, Kind),
 
+    ///
+    /// A sub-program tried to read input, which only the main program may do.
+    ///
+    #[error("sub-programs cannot read input")]
+    InputInBlock,
+
     ///
     /// The queue was shorter than expected.
     ///
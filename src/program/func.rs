@@ -1,64 +1,114 @@
 use super::{ArgMismatchError, Error, Kind, Value};
-use crate::helper::{AsciiExt, EolinaIndex, EolinaRange};
-use crate::parse::{CheckToken, MapToken};
+use crate::helper::{
+    self, AsciiExt, CaseExt, EolinaIndex, EolinaRange, Glob, IndexError, Literal, Rng, Template,
+    UnicodeExt,
+};
+use crate::parse::{CastToken, CheckToken, ExtremumToken, MapToken, MetricToken, RadixToken};
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Range,
+};
 
 ///
-/// Splits the given input into it's [`char`]s if no `split` is given otherwise splits by `split`.
+/// Converts the given raw input into a single [`String`], line endings are normalized to `\n` and
+/// a single trailing line ending is removed.
+///
+/// ### Returns
+///
+/// * [`Value::String(string)`]
+///   * `string` contains the normalized input
+///
+pub fn read_string(raw: &str) -> Value {
+    Value::String(raw.lines().collect::<Vec<_>>().join("\n"))
+}
+
+///
+/// Converts the given raw input into it's lines, both `\n` and `\r\n` end a line and the last
+/// line needs no line ending.
+///
+/// ### Returns
+///
+/// * [`Value::StringVec(vec)`]
+///   * `vec` contains the lines without their line endings
+///
+pub fn read_lines(raw: &str) -> Value {
+    Value::StringVec(raw.lines().map(ToOwned::to_owned).collect())
+}
+
+///
+/// Splits the given input into it's [`char`]s if no `split` is given otherwise splits by `split`,
+/// every element of a [`Kind::StringVec`] is split into a row.
 ///
 /// ### Accepts
 ///
 /// * [`Kind::String`]
+/// * [`Kind::StringVec`]
 ///
 /// ### Returns
 ///
 /// * [`Ok(Value::StringVec(vec))`]
 ///   * `vec` contains the input's [`char`]s, each as a separate [`String`]
+/// * [`Ok(Value::StringVecVec(vec))`]
+///   * `vec` contains the split elements of the input
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
 ///
-pub fn split(input: Value, split: Option<&str>) -> Result<Value, Error> {
-    let string = match input {
-        Value::String(inner) => Ok(inner),
+pub fn split(input: Value, split: Option<Literal>) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::StringVec(__split(&string, split))),
+        Value::StringVec(vec) => Ok(Value::StringVecVec(
+            vec.iter().map(|string| __split(string, split)).collect(),
+        )),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String],
+            &[Kind::String, Kind::StringVec],
             x.kind(),
         ))),
-    }?;
+    }
+}
 
-    Ok(Value::StringVec(match split {
-        Some(split) => string
-            .split(&split)
+fn __split(string: &str, split: Option<Literal>) -> Vec<String> {
+    match split {
+        Some(split) => split
+            .split(string)
+            .into_iter()
             .filter(|str| !str.is_empty())
             .map(ToOwned::to_owned)
             .collect(),
         None => string.chars().map(|ch| ch.to_string()).collect(),
-    }))
+    }
 }
 
 ///
-/// Joins the given input into one [`String`].
+/// Joins the given input into one [`String`], every row of a [`Kind::StringVecVec`] is joined
+/// into an element.
 ///
 /// ### Accepts
 ///
 /// * [`Kind::StringVec`]
+/// * [`Kind::StringVecVec`]
 ///
 /// ### Returns
 ///
 /// * [`Ok(Value::String(string))`]
 ///   * `string` contains the input's [`char`]s, each as a separate [`String`]
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the joined rows of the input
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
 ///
 pub fn join(input: Value) -> Result<Value, Error> {
-    let vec = match input {
-        Value::StringVec(inner) => Ok(inner),
+    match input {
+        Value::StringVec(vec) => Ok(Value::String(vec.into_iter().collect::<String>())),
+        Value::StringVecVec(vec) => Ok(Value::StringVec(
+            vec.into_iter()
+                .map(|row| row.into_iter().collect::<String>())
+                .collect(),
+        )),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::StringVec],
+            &[Kind::StringVec, Kind::StringVecVec],
             x.kind(),
         ))),
-    }?;
-
-    Ok(Value::String(vec.into_iter().collect::<String>()))
+    }
 }
 
 ///
@@ -68,6 +118,7 @@ pub fn join(input: Value) -> Result<Value, Error> {
 ///
 /// * [`Kind::String`]
 /// * [`Kind::StringVec`]
+/// * [`Kind::StringVecVec`], rows are appended
 ///
 /// ### Returns
 ///
@@ -86,18 +137,114 @@ pub fn concat(input1: Value, input2: Value) -> Result<Value, Error> {
             vec1.append(&mut vec2);
             vec1
         })),
+        (Value::StringVecVec(mut vec1), Value::StringVecVec(mut vec2)) => {
+            Ok(Value::StringVecVec({
+                vec1.append(&mut vec2);
+                vec1
+            }))
+        }
         (x, Value::Bool(_)) => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
+            &[Kind::String, Kind::StringVec, Kind::StringVecVec],
             x.kind(),
         ))),
         (Value::Bool(_), x) => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
+            &[Kind::String, Kind::StringVec, Kind::StringVecVec],
             x.kind(),
         ))),
         x => Err(Error::Mismatch(x.0.kind(), x.1.kind())),
     }
 }
 
+///
+/// Compares the given inputs by a similarity `metric`.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] and [`Kind::String`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the distance for [`MetricToken::Levenshtein`] and
+///     [`MetricToken::Damerau`] or the similarity rounded to 4 decimal places for
+///     [`MetricToken::JaroWinkler`]
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a too large [`Error`] if the table of [`MetricToken::Damerau`] would
+///     exceed `max_size`
+///
+pub fn similarity(
+    input1: Value,
+    input2: Value,
+    metric: MetricToken,
+    max_size: usize,
+) -> Result<Value, Error> {
+    let (left, right) = __string_pair(input1, input2)?;
+
+    if metric == MetricToken::Damerau {
+        // the table has an additional border row and column
+        __check_table(
+            left.chars().count() + 2,
+            right.chars().count() + 2,
+            max_size,
+        )?;
+    }
+
+    Ok(Value::String(match metric {
+        MetricToken::Levenshtein => helper::levenshtein(&left, &right).to_string(),
+        MetricToken::Damerau => helper::damerau(&left, &right).to_string(),
+        MetricToken::JaroWinkler => format!("{:.4}", helper::jaro_winkler(&left, &right)),
+    }))
+}
+
+///
+/// Creates a unified diff of the lines of the given inputs.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] and [`Kind::String`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the lines of the diff, it is empty if the inputs are equal
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a too large [`Error`] if the table of common lines would exceed
+///     `max_size`
+///
+pub fn diff(input1: Value, input2: Value, max_size: usize) -> Result<Value, Error> {
+    let (left, right) = __string_pair(input1, input2)?;
+    __check_table(
+        left.lines().count() + 1,
+        right.lines().count() + 1,
+        max_size,
+    )?;
+    Ok(Value::StringVec(helper::unified_diff(&left, &right)))
+}
+
+fn __check_table(rows: usize, cols: usize, max_size: usize) -> Result<(), Error> {
+    let size = rows
+        .checked_mul(cols)
+        .and_then(|cells| cells.checked_mul(std::mem::size_of::<usize>()))
+        .unwrap_or(usize::MAX);
+    if size > max_size {
+        return Err(Error::TooLarge(size, max_size));
+    }
+
+    Ok(())
+}
+
+fn __string_pair(input1: Value, input2: Value) -> Result<(String, String), Error> {
+    match (input1, input2) {
+        (Value::String(left), Value::String(right)) => Ok((left, right)),
+        (Value::String(_), x) | (x, _) => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String],
+            x.kind(),
+        ))),
+    }
+}
+
 ///
 /// Returns whether or not each element in the given input is a consonant or contains itself only
 /// consonants.
@@ -176,6 +323,25 @@ pub fn is_lower(input: Value) -> Result<Value, Error> {
     __check_all(input, |s| s.is_lower())
 }
 
+///
+/// Returns whether or not each element in the given input matches the given glob.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::Bool(value))`]
+///   * `value` contains whether or not the check succeeded
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn is_glob(input: Value, glob: &Glob) -> Result<Value, Error> {
+    __check_all(input, |s| glob.is_match(s))
+}
+
 fn __check_all(input: Value, check: impl Fn(&String) -> bool) -> Result<Value, Error> {
     match input {
         Value::String(string) => Ok(Value::Bool(check(&string))),
@@ -201,12 +367,19 @@ fn __check_all(input: Value, check: impl Fn(&String) -> bool) -> Result<Value, E
 ///   * `stringOrVec` contains the mapped input
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a decode or unescape [`Error`]
+///   * `error` contains a char or char code [`Error`]
+///   * `error` contains a run-length [`Error`] if a decoded run-length encoding is invalid
+///   * `error` contains a too large [`Error`] if a padded or run-length decoded output would
+///     exceed `max_size`
 ///
-pub fn map(input: Value, map: MapToken) -> Result<Value, Error> {
+pub fn map(input: Value, map: MapToken, max_size: usize) -> Result<Value, Error> {
     match input {
-        Value::String(string) => Ok(Value::String(__map(string, map))),
+        Value::String(string) => Ok(Value::String(__map(string, map, max_size)?)),
         Value::StringVec(vec) => Ok(Value::StringVec(
-            vec.into_iter().map(|string| __map(string, map)).collect(),
+            vec.into_iter()
+                .map(|string| __map(string, map, max_size))
+                .collect::<Result<_, _>>()?,
         )),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
             &[Kind::String, Kind::StringVec],
@@ -215,99 +388,246 @@ pub fn map(input: Value, map: MapToken) -> Result<Value, Error> {
     }
 }
 
-fn __map<T: AsciiExt>(val: T, map: MapToken) -> T {
-    match map {
+fn __map(val: String, map: MapToken, max_size: usize) -> Result<String, Error> {
+    Ok(match map {
         MapToken::Lower => val.into_lower(),
         MapToken::Upper => val.into_upper(),
         MapToken::Swap => val.into_swap(),
+        MapToken::Title => val.into_title(),
+        MapToken::Capitalize => val.into_capitalized(),
+        MapToken::Snake => val.into_snake(),
+        MapToken::Screaming => val.into_screaming(),
+        MapToken::Kebab => val.into_kebab(),
+        MapToken::Camel => val.into_camel(),
+        MapToken::Pascal => val.into_pascal(),
+        MapToken::Nfc => val.into_nfc(),
+        MapToken::Nfd => val.into_nfd(),
+        MapToken::Nfkc => val.into_nfkc(),
+        MapToken::Nfkd => val.into_nfkd(),
+        MapToken::NoDiacritics => val.into_stripped(),
+        MapToken::Ascii => val.into_ascii(),
+        MapToken::Trim(chars) => __trim(&val, chars, true, true),
+        MapToken::TrimStart(chars) => __trim(&val, chars, true, false),
+        MapToken::TrimEnd(chars) => __trim(&val, chars, false, true),
+        MapToken::PadLeft(width, fill) => __pad(val, width, fill, 1, 0, max_size)?,
+        MapToken::PadRight(width, fill) => __pad(val, width, fill, 0, 1, max_size)?,
+        MapToken::PadCenter(width, fill) => __pad(val, width, fill, 1, 1, max_size)?,
+        MapToken::Truncate(width) => __truncate(val, width),
+        MapToken::Rotate(shift) => val.into_rotated(shift),
+        MapToken::Atbash => val.into_atbash(),
+        MapToken::Ord(radix) => {
+            let mut chars = val.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => __ord(ch, radix),
+                _ => return Err(Error::NotChar(val)),
+            }
+        }
+        MapToken::Chr(radix) => __chr(&val, radix)?.to_string(),
+        MapToken::Encode(encoding) => encoding.encode(&val),
+        MapToken::Decode(encoding) => encoding.decode(&val)?,
+        MapToken::Escape(format) => format.escape(&val),
+        MapToken::Unescape(format) => format.unescape(&val)?,
+        MapToken::RunLength => __run_length_encode(&val),
+        MapToken::RunLengthDecode => __run_length_decode(&val, max_size)?,
+    })
+}
+
+fn __run_length_encode(val: &str) -> String {
+    let mut ret = String::new();
+    let mut chars = val.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&ch).is_some() {
+            count += 1;
+        }
+
+        ret.push_str(&count.to_string());
+
+        // escape chars which would be read as part of the count
+        if ch.is_ascii_digit() || ch == '\\' {
+            ret.push('\\');
+        }
+        ret.push(ch);
+    }
+
+    ret
+}
+
+fn __run_length_decode(val: &str, max_size: usize) -> Result<String, Error> {
+    let err = || Error::RunLength(val.to_owned());
+
+    let mut ret = String::new();
+    let mut rest = val;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let count = rest[..digits].parse::<usize>().map_err(|_| err())?;
+
+        let mut chars = rest[digits..].chars();
+        let ch = match chars.next() {
+            Some('\\') => chars.next(),
+            ch => ch,
+        }
+        .ok_or_else(err)?;
+        rest = chars.as_str();
+
+        let size = count
+            .checked_mul(ch.len_utf8())
+            .and_then(|size| size.checked_add(ret.len()))
+            .unwrap_or(usize::MAX);
+        if size > max_size {
+            return Err(Error::TooLarge(size, max_size));
+        }
+
+        ret.extend(std::iter::repeat(ch).take(count));
+    }
+
+    Ok(ret)
+}
+
+fn __trim(val: &str, chars: Option<&str>, start: bool, end: bool) -> String {
+    let is_trimmed = |ch: char| match chars {
+        Some(chars) => chars.contains(ch),
+        None => ch.is_whitespace(),
+    };
+
+    let mut trimmed = val;
+    if start {
+        trimmed = trimmed.trim_start_matches(is_trimmed);
+    }
+    if end {
+        trimmed = trimmed.trim_end_matches(is_trimmed);
+    }
+
+    trimmed.to_owned()
+}
+
+fn __pad(
+    val: String,
+    width: usize,
+    fill: char,
+    left: usize,
+    right: usize,
+    max_size: usize,
+) -> Result<String, Error> {
+    let len = val.chars().count();
+    if len >= width {
+        return Ok(val);
+    }
+
+    let missing = width - len;
+    let size = missing
+        .checked_mul(fill.len_utf8())
+        .and_then(|size| size.checked_add(val.len()))
+        .unwrap_or(usize::MAX);
+    if size > max_size {
+        return Err(Error::TooLarge(size, max_size));
+    }
+
+    // the left side gets the smaller half if both sides are padded
+    let left = missing * left / (left + right);
+    let right = missing - left;
+
+    let mut ret = String::with_capacity(val.len() + missing * fill.len_utf8());
+    ret.extend(std::iter::repeat(fill).take(left));
+    ret.push_str(&val);
+    ret.extend(std::iter::repeat(fill).take(right));
+    Ok(ret)
+}
+
+fn __ord(ch: char, radix: RadixToken) -> String {
+    match radix {
+        RadixToken::Dec => format!("{}", ch as u32),
+        RadixToken::Hex => format!("{:x}", ch as u32),
+        RadixToken::Bin => format!("{:b}", ch as u32),
+    }
+}
+
+fn __chr(code: &str, radix: RadixToken) -> Result<char, Error> {
+    u32::from_str_radix(code, radix.base())
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| Error::CharCode(code.to_owned()))
+}
+
+fn __truncate(val: String, width: usize) -> String {
+    const ELLIPSIS: &str = "...";
+
+    if val.chars().count() <= width {
+        return val;
     }
+
+    let kept = width.saturating_sub(ELLIPSIS.len());
+    val.chars()
+        .take(kept)
+        .chain(ELLIPSIS.chars().take(width - kept))
+        .collect()
 }
 
 ///
-/// Filters out each element in the given input that does not pass a given check.
+/// Converts the given input into the codes of it's [`char`]s, this is equivalent to splitting it
+/// into [`char`]s and mapping each by `ord`.
 ///
 /// ### Accepts
 ///
 /// * [`Kind::String`]
-/// * [`Kind::StringVec`]
 ///
 /// ### Returns
 ///
-/// * [`Ok(stringOrVec)`]
-///   * `stringOrVec` contains the filtered input
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the code of each [`char`] in the given `radix`
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
 ///
-pub fn filter(input: Value, check: CheckToken) -> Result<Value, Error> {
+pub fn codes(input: Value, radix: RadixToken) -> Result<Value, Error> {
     match input {
-        Value::String(string) => Ok(Value::String(
-            string
-                .chars()
-                .filter(|ch| __filter(ch, check))
-                .collect::<String>(),
-        )),
-        Value::StringVec(vec) => Ok(Value::StringVec(
-            vec.into_iter()
-                .filter(|string| __filter(string, check))
-                .collect(),
+        Value::String(string) => Ok(Value::StringVec(
+            string.chars().map(|ch| __ord(ch, radix)).collect(),
         )),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
+            &[Kind::String],
             x.kind(),
         ))),
     }
 }
 
-fn __filter<T: AsciiExt>(val: &T, check: CheckToken) -> bool {
-    match check {
-        CheckToken::Vowel => val.is_vowel(),
-        CheckToken::Conso => val.is_conso(),
-        CheckToken::Upper => val.is_upper(),
-        CheckToken::Lower => val.is_lower(),
-    }
-}
-
 ///
-/// Extracts the element at the given `index`.
+/// Converts the given char codes back into a [`String`], this is equivalent to mapping each code
+/// by `chr` and joining them.
 ///
 /// ### Accepts
 ///
-/// * [`Kind::String`]
 /// * [`Kind::StringVec`]
 ///
 /// ### Returns
 ///
 /// * [`Ok(Value::String(string))`]
-///   * `string` contains the element at the given index
+///   * `string` contains the [`char`]s of the codes in the given `radix`
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a char code [`Error`]
 ///
-pub fn index(input: Value, index: EolinaIndex) -> Result<Value, Error> {
-    let len = match &input {
-        Value::String(inner) => Ok(inner.len()),
-        Value::StringVec(inner) => Ok(inner.len()),
-        x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
-            x.kind(),
-        ))),
-    }?;
-
-    let index = index.as_usize(len)?;
-
+pub fn chars(input: Value, radix: RadixToken) -> Result<Value, Error> {
     match input {
-        Value::String(string) => Ok(Value::String(
-            String::from_utf8_lossy(&string.as_bytes()[index..=index]).into_owned(),
+        Value::StringVec(vec) => Ok(Value::String(
+            vec.iter()
+                .map(|code| __chr(code, radix))
+                .collect::<Result<_, _>>()?,
         )),
-        Value::StringVec(vec) => Ok(Value::String(vec[index].to_owned())),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
+            &[Kind::StringVec],
             x.kind(),
         ))),
     }
 }
 
 ///
-/// Slices the given input at the lower and upper bounds.
+/// Repeats the given input `count` times, a [`Kind::StringVec`] is repeated as a whole.
+///
+/// The size of the output is it's length in bytes, every element of a [`Kind::StringVec`] counts
+/// one more byte for it's separator.
 ///
 /// ### Accepts
 ///
@@ -317,168 +637,2705 @@ pub fn index(input: Value, index: EolinaIndex) -> Result<Value, Error> {
 /// ### Returns
 ///
 /// * [`Ok(stringOrVec)`]
-///   * `stringOrVec` contains the subslice of the input
+///   * `stringOrVec` contains the repeated input
 /// * [`Err(error)`]
 ///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a too large [`Error`] if the output would exceed `max_size`
+///
+pub fn repeat(input: Value, count: usize, max_size: usize) -> Result<Value, Error> {
+    let size = match &input {
+        Value::String(string) => string.len(),
+        Value::StringVec(vec) => vec.iter().map(|string| string.len() + 1).sum(),
+        x => {
+            return Err(Error::ArgMismatch(ArgMismatchError::new(
+                &[Kind::String, Kind::StringVec],
+                x.kind(),
+            )))
+        }
+    };
+
+    let size = size.checked_mul(count).unwrap_or(usize::MAX);
+    if size > max_size {
+        return Err(Error::TooLarge(size, max_size));
+    }
+
+    Ok(match input {
+        Value::String(string) => Value::String(string.repeat(count)),
+        Value::StringVec(vec) => Value::StringVec(
+            vec.iter()
+                .cycle()
+                .take(vec.len() * count)
+                .cloned()
+                .collect(),
+        ),
+        _ => unreachable!("checked above"),
+    })
+}
+
 ///
-pub fn slice(input: Value, range: EolinaRange) -> Result<Value, Error> {
-    let len = match &input {
-        Value::String(inner) => Ok(inner.len()),
-        Value::StringVec(inner) => Ok(inner.len()),
+/// Repeats the given input by a `count` value, see [`repeat`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] or [`Kind::StringVec`] as `input`
+/// * [`Kind::String`] as `count`
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the repeated input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a count [`Error`] if `count` is not a [`usize`]
+///   * `error` contains a too large [`Error`] if the output would exceed `max_size`
+///
+pub fn repeat_by(input: Value, count: Value, max_size: usize) -> Result<Value, Error> {
+    let count = match count {
+        Value::String(string) => string.parse().map_err(|_| Error::NotCount(string)),
         x => Err(Error::ArgMismatch(ArgMismatchError::new(
-            &[Kind::String, Kind::StringVec],
+            &[Kind::String],
             x.kind(),
         ))),
     }?;
 
-    let range = range.as_range(len)?;
+    repeat(input, count, max_size)
+}
 
-    match input {
-        Value::String(string) => Ok(Value::String(string[range].to_owned())),
-        Value::StringVec(vec) => Ok(Value::StringVec(vec[range].to_owned())),
-        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+///
+/// Renders the given template with the given values, a placeholder's separator joins the
+/// elements of a [`Kind::StringVec`] and the elements of every row of a [`Kind::StringVecVec`],
+/// defaulting to a space. The rows of a [`Kind::StringVecVec`] are joined by newlines.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+/// * [`Kind::StringVecVec`]
+/// * [`Kind::Bool`]
+///
+/// ### Returns
+///
+/// * [`Value::String(string)`]
+///   * `string` contains the rendered template
+///
+/// ### Panics
+///
+/// Panics if fewer values than the [`Template::arity`] are given.
+///
+pub fn template(template: &Template, values: &[Value]) -> Value {
+    Value::String(template.render(values, |value, sep| __to_string(value, sep.unwrap_or(" "))))
+}
+
+fn __to_string(value: &Value, sep: &str) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        Value::StringVec(vec) => vec.join(sep),
+        Value::StringVecVec(vec) => vec
+            .iter()
+            .map(|row| row.join(sep))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Value::Bool(bool) => bool.to_string(),
+    }
+}
+
+///
+/// Converts the given input by `cast`.
+///
+/// * [`CastToken::String`] joins elements by spaces and rows by newlines
+/// * [`CastToken::List`] wraps a [`Kind::String`] or [`Kind::Bool`] in a single element
+///   [`Kind::StringVec`] and flattens a [`Kind::StringVecVec`]
+/// * [`CastToken::Bool`] is `false` for empty values and the strings `""`, `"0"` and `"false"`
+/// * [`CastToken::Number`] parses a number, or every element, and formats it canonically
+/// * [`CastToken::Kind`] returns the name of the input's [`Kind`]
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+/// * [`Kind::StringVecVec`]
+/// * [`Kind::Bool`]
+///
+/// ### Returns
+///
+/// * [`Ok(value)`]
+///   * `value` contains the converted input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`] for a [`CastToken::Number`] of a
+///     [`Kind::StringVecVec`] or [`Kind::Bool`] or a not a number [`Error`]
+///
+pub fn cast(input: Value, cast: CastToken) -> Result<Value, Error> {
+    match (cast, input) {
+        (CastToken::String, x) => Ok(Value::String(__to_string(&x, " "))),
+        (CastToken::List, Value::String(string)) => Ok(Value::StringVec(vec![string])),
+        (CastToken::List, Value::StringVecVec(vec)) => {
+            Ok(Value::StringVec(vec.into_iter().flatten().collect()))
+        }
+        (CastToken::List, Value::Bool(bool)) => Ok(Value::StringVec(vec![bool.to_string()])),
+        (CastToken::List, x) => Ok(x),
+        (CastToken::Bool, x) => Ok(Value::Bool(match x {
+            Value::String(string) => !matches!(string.as_str(), "" | "0" | "false"),
+            Value::StringVec(vec) => !vec.is_empty(),
+            Value::StringVecVec(vec) => !vec.is_empty(),
+            Value::Bool(bool) => bool,
+        })),
+        (CastToken::Number, Value::String(string)) => Ok(Value::String(__number(&string)?)),
+        (CastToken::Number, Value::StringVec(vec)) => Ok(Value::StringVec(
+            vec.iter()
+                .map(|string| __number(string))
+                .collect::<Result<_, _>>()?,
+        )),
+        (CastToken::Number, x) => Err(Error::ArgMismatch(ArgMismatchError::new(
             &[Kind::String, Kind::StringVec],
             x.kind(),
         ))),
+        (CastToken::Kind, x) => Ok(Value::String(x.kind().to_string())),
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+fn __number(input: &str) -> Result<String, Error> {
+    let trimmed = input.trim();
 
-    #[test]
-    fn split() {
-        assert_eq!(
-            super::split(Value::String("Abc".to_owned()), None).unwrap(),
-            Value::StringVec(vec!["A".to_owned(), "b".to_owned(), "c".to_owned()])
-        );
-        assert_eq!(
-            super::split(Value::String("Abcbdebfb".to_owned()), Some("b")).unwrap(),
-            Value::StringVec(vec![
-                "A".to_owned(),
-                "c".to_owned(),
-                "de".to_owned(),
-                "f".to_owned()
-            ])
-        );
-        assert!(super::split(Value::Bool(true), None).is_err());
+    // prefer integers to keep their full precision
+    if let Ok(int) = trimmed.parse::<i128>() {
+        return Ok(int.to_string());
+    }
+
+    match trimmed.parse::<f64>() {
+        Ok(float) if float.is_finite() => Ok(float.to_string()),
+        _ => Err(Error::NotNumber(input.to_owned())),
+    }
+}
+
+///
+/// Creates the numbers from the inclusive `start` to the exclusive `end` with distance `step`,
+/// counting down if `start` is greater than `end`.
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the numbers
+/// * [`Err(error)`]
+///   * `error` contains a too large [`Error`] if the output would exceed `max_size`, see
+///     [`repeat`]
+///
+pub fn sequence(start: isize, end: isize, step: usize, max_size: usize) -> Result<Value, Error> {
+    let diff = start.abs_diff(end);
+    let len = diff / step + if diff % step == 0 { 0 } else { 1 };
+
+    // every offset is less than `diff`, so each value lies between `start` and `end`
+    let values = (0..len).map(|idx| {
+        let offset = (idx * step) as i128;
+        if start <= end {
+            (start as i128 + offset) as isize
+        } else {
+            (start as i128 - offset) as isize
+        }
+    });
+
+    __generate(values, max_size)
+}
+
+///
+/// Creates the [`char`]s from the inclusive `start` to the inclusive `end`, counting down if
+/// `start` is greater than `end`.
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the [`char`]s
+/// * [`Err(error)`]
+///   * `error` contains a too large [`Error`] if the output would exceed `max_size`, see
+///     [`repeat`]
+///
+pub fn char_range(start: char, end: char, max_size: usize) -> Result<Value, Error> {
+    let (low, high) = (start.min(end) as u32, start.max(end) as u32);
+
+    // surrogates are skipped
+    let chars = (low..=high).filter_map(char::from_u32);
+    if start <= end {
+        __generate(chars, max_size)
+    } else {
+        __generate(chars.rev(), max_size)
+    }
+}
+
+fn __generate<T: ToString>(
+    values: impl Iterator<Item = T>,
+    max_size: usize,
+) -> Result<Value, Error> {
+    let mut size = 0;
+    let mut ret = vec![];
+
+    for value in values {
+        let value = value.to_string();
+        size += value.len() + 1;
+        if size > max_size {
+            return Err(Error::TooLarge(size, max_size));
+        }
+
+        ret.push(value);
+    }
+
+    Ok(Value::StringVec(ret))
+}
+
+///
+/// Picks a random element of the given input.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the picked element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains an empty [`Error`] if the input has no elements
+///
+pub fn choice(input: Value, rng: &Rng) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => {
+            let chars = string.chars().collect::<Vec<_>>();
+            if chars.is_empty() {
+                Err(Error::Empty(Kind::String))
+            } else {
+                Ok(Value::String(chars[rng.below(chars.len())].to_string()))
+            }
+        }
+        Value::StringVec(mut vec) => {
+            if vec.is_empty() {
+                Err(Error::Empty(Kind::StringVec))
+            } else {
+                let idx = rng.below(vec.len());
+                Ok(Value::String(vec.swap_remove(idx)))
+            }
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Picks `count` distinct random elements of the given input in random order, if the input has
+/// fewer elements all are picked.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the picked elements
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn sample(input: Value, count: usize, rng: &Rng) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => {
+            let mut chars = string.chars().collect::<Vec<_>>();
+            rng.partial_shuffle(&mut chars, count);
+            chars.truncate(count);
+            Ok(Value::String(chars.into_iter().collect()))
+        }
+        Value::StringVec(mut vec) => {
+            rng.partial_shuffle(&mut vec, count);
+            vec.truncate(count);
+            Ok(Value::StringVec(vec))
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Shuffles the elements of the given input.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the shuffled input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn shuffle(input: Value, rng: &Rng) -> Result<Value, Error> {
+    sample(input, usize::MAX, rng)
+}
+
+///
+/// Counts the elements in the given input that pass a given check.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the number of elements passing the check
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn count(input: Value, check: CheckToken) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::String(
+            string
+                .chars()
+                .filter(|ch| __filter(ch, check))
+                .count()
+                .to_string(),
+        )),
+        Value::StringVec(vec) => Ok(Value::String(
+            vec.iter()
+                .filter(|string| __filter(*string, check))
+                .count()
+                .to_string(),
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Counts the non-overlapping occurences of `literal` in a [`Kind::String`] or the elements equal
+/// to `literal` in a [`Kind::StringVec`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the number of occurences
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn count_literal(input: Value, literal: Literal) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::String(literal.matches(&string).len().to_string())),
+        Value::StringVec(vec) => Ok(Value::String(
+            vec.iter()
+                .filter(|string| literal.is_eq(string))
+                .count()
+                .to_string(),
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Selects the element of the given input by `extremum`, ties are broken by taking the first
+/// element.
+///
+/// ### Accepts
+///
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the selected element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch or empty [`Error`]
+///
+pub fn select(input: Value, extremum: ExtremumToken) -> Result<Value, Error> {
+    match input {
+        Value::StringVec(mut vec) => {
+            let idx = __select(&vec, extremum)?;
+            Ok(Value::String(vec.swap_remove(idx)))
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Selects the index of the element of the given input by `extremum`, ties are broken by taking
+/// the first element.
+///
+/// ### Accepts
+///
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the index of the selected element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch or empty [`Error`]
+///
+pub fn select_index(input: Value, extremum: ExtremumToken) -> Result<Value, Error> {
+    match input {
+        Value::StringVec(vec) => Ok(Value::String(__select(&vec, extremum)?.to_string())),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __select(vec: &[String], extremum: ExtremumToken) -> Result<usize, Error> {
+    let len = |string: &String| string.chars().count();
+
+    // keep the first element on ties by only replacing on strict improvement
+    let mut iter = vec.iter().enumerate();
+    let (mut best_idx, mut best) = iter.next().ok_or(Error::Empty(Kind::StringVec))?;
+    for (idx, string) in iter {
+        let better = match extremum {
+            ExtremumToken::Min => string < best,
+            ExtremumToken::Max => string > best,
+            ExtremumToken::Shortest => len(string) < len(best),
+            ExtremumToken::Longest => len(string) > len(best),
+        };
+
+        if better {
+            best_idx = idx;
+            best = string;
+        }
+    }
+
+    Ok(best_idx)
+}
+
+///
+/// Groups consecutive equal [`char`]s of the given input into runs, or consecutive equal elements
+/// into rows.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the runs of equal [`char`]s
+/// * [`Ok(Value::StringVecVec(vec))`]
+///   * `vec` contains the rows of equal elements
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn group(input: Value) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::StringVec(
+            __group(string.chars())
+                .into_iter()
+                .map(|run| run.into_iter().collect())
+                .collect(),
+        )),
+        Value::StringVec(vec) => Ok(Value::StringVecVec(__group(vec))),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __group<T: PartialEq>(values: impl IntoIterator<Item = T>) -> Vec<Vec<T>> {
+    let mut ret: Vec<Vec<T>> = vec![];
+    for value in values {
+        match ret.last_mut() {
+            Some(run) if run[0] == value => run.push(value),
+            _ => ret.push(vec![value]),
+        }
+    }
+
+    ret
+}
+
+///
+/// Creates a frequency table of the elements in the given input, the table is sorted by
+/// descending count, elements of equal count are sorted ascending.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains an `element count` pair for each distinct element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn frequency(input: Value) -> Result<Value, Error> {
+    let vec = match input {
+        Value::String(string) => Ok(string.chars().map(|ch| ch.to_string()).collect()),
+        Value::StringVec(vec) => Ok(vec),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }?;
+
+    let mut counts = BTreeMap::new();
+    for string in vec {
+        *counts.entry(string).or_insert(0usize) += 1;
+    }
+
+    // stable sort keeps the ascending order of the map for equal counts
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    Ok(Value::StringVec(
+        counts
+            .into_iter()
+            .map(|(string, count)| format!("{} {}", string, count))
+            .collect(),
+    ))
+}
+
+///
+/// Maps each element in the given input with a given function, a [`Kind::String`] input is
+/// mapped [`char`] by [`char`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the mapped input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains the error returned by `f`
+///
+pub fn map_with<E: From<Error>>(
+    input: Value,
+    mut f: impl FnMut(Value) -> Result<Value, E>,
+) -> Result<Value, E> {
+    let mut map = |string| match f(Value::String(string))? {
+        Value::String(inner) => Ok(inner),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(&[Kind::String], x.kind())).into()),
+    };
+
+    match input {
+        Value::String(string) => Ok(Value::String(
+            string
+                .chars()
+                .map(|ch| map(ch.to_string()))
+                .collect::<Result<String, E>>()?,
+        )),
+        Value::StringVec(vec) => Ok(Value::StringVec(
+            vec.into_iter().map(map).collect::<Result<_, E>>()?,
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))
+        .into()),
+    }
+}
+
+///
+/// Filters out each element in the given input that does not pass a given check.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the filtered input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn filter(input: Value, check: CheckToken) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::String(
+            string
+                .chars()
+                .filter(|ch| __filter(ch, check))
+                .collect::<String>(),
+        )),
+        Value::StringVec(vec) => Ok(Value::StringVec(
+            vec.into_iter()
+                .filter(|string| __filter(string, check))
+                .collect(),
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Filters the elements of the given input by the given glob.
+///
+/// ### Accepts
+///
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::StringVec(vec))`]
+///   * `vec` contains the matching elements
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn filter_glob(input: Value, glob: &Glob) -> Result<Value, Error> {
+    match input {
+        Value::StringVec(vec) => Ok(Value::StringVec(
+            vec.into_iter()
+                .filter(|string| glob.is_match(string))
+                .collect(),
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Partitions the given input into the elements that pass a given check and those that don't in
+/// one pass.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok([pass, fail])`]
+///   * `pass` contains the elements passing the check
+///   * `fail` contains the elements not passing the check
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn partition(input: Value, check: CheckToken) -> Result<[Value; 2], Error> {
+    match input {
+        Value::String(string) => {
+            let (pass, fail): (String, String) = string.chars().partition(|ch| __filter(ch, check));
+            Ok([Value::String(pass), Value::String(fail)])
+        }
+        Value::StringVec(vec) => {
+            let (pass, fail) = vec.into_iter().partition(|string| __filter(string, check));
+            Ok([Value::StringVec(pass), Value::StringVec(fail)])
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __filter<T: AsciiExt>(val: &T, check: CheckToken) -> bool {
+    match check {
+        CheckToken::Vowel => val.is_vowel(),
+        CheckToken::Conso => val.is_conso(),
+        CheckToken::Upper => val.is_upper(),
+        CheckToken::Lower => val.is_lower(),
+    }
+}
+
+///
+/// Filters out each element in the given input for which the given function does not return
+/// `true`, a [`Kind::String`] input is filtered [`char`] by [`char`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the filtered input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains the error returned by `f`
+///
+pub fn filter_with<E: From<Error>>(
+    input: Value,
+    mut f: impl FnMut(Value) -> Result<Value, E>,
+) -> Result<Value, E> {
+    let mut check = |string: &String| -> Result<bool, E> {
+        match f(Value::String(string.to_owned()))? {
+            Value::Bool(inner) => Ok(inner),
+            x => Err(Error::ArgMismatch(ArgMismatchError::new(&[Kind::Bool], x.kind())).into()),
+        }
+    };
+
+    match input {
+        Value::String(string) => {
+            let mut ret = String::with_capacity(string.len());
+            for ch in string.chars() {
+                if check(&ch.to_string())? {
+                    ret.push(ch);
+                }
+            }
+
+            Ok(Value::String(ret))
+        }
+        Value::StringVec(vec) => {
+            let mut ret = Vec::with_capacity(vec.len());
+            for string in vec {
+                if check(&string)? {
+                    ret.push(string);
+                }
+            }
+
+            Ok(Value::StringVec(ret))
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))
+        .into()),
+    }
+}
+
+///
+/// Folds the given input into an accumulator starting at `init` by calling a given function with
+/// the accumulator and each element, a [`Kind::String`] input is folded [`char`] by [`char`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(value)`]
+///   * `value` contains the final accumulator
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains the error returned by `f`
+///
+pub fn fold_with<E: From<Error>>(
+    input: Value,
+    init: Value,
+    mut f: impl FnMut(Value, Value) -> Result<Value, E>,
+) -> Result<Value, E> {
+    match input {
+        Value::String(string) => string
+            .chars()
+            .try_fold(init, |acc, ch| f(acc, Value::String(ch.to_string()))),
+        Value::StringVec(vec) => vec
+            .into_iter()
+            .try_fold(init, |acc, string| f(acc, Value::String(string))),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))
+        .into()),
+    }
+}
+
+///
+/// Finds the index of the first occurence of `literal` in a [`Kind::String`] or the first element
+/// equal to `literal` in a [`Kind::StringVec`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the absolute index of the occurence
+/// * [`Ok(Value::Bool(false))`]
+///   * `literal` was not found
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn find(input: Value, literal: Literal) -> Result<Value, Error> {
+    __find(
+        input,
+        |string| literal.find(string),
+        |vec| vec.iter().position(|string| literal.is_eq(string)),
+    )
+}
+
+///
+/// Finds the index of the last occurence of `literal` in a [`Kind::String`] or the last element
+/// equal to `literal` in a [`Kind::StringVec`].
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the absolute index of the occurence
+/// * [`Ok(Value::Bool(false))`]
+///   * `literal` was not found
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn rfind(input: Value, literal: Literal) -> Result<Value, Error> {
+    __find(
+        input,
+        |string| literal.rfind(string),
+        |vec| vec.iter().rposition(|string| literal.is_eq(string)),
+    )
+}
+
+///
+/// Checks if the given input equals the `literal`.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::Bool(bool))`]
+///   * `bool` is `true` if the [`Kind::String`] or all elements equal the `literal`
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn equals(input: Value, literal: Literal) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::Bool(literal.is_eq(&string))),
+        Value::StringVec(vec) => Ok(Value::Bool(vec.iter().all(|string| literal.is_eq(string)))),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Checks if the given input contains the `literal`.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::Bool(bool))`]
+///   * `bool` is `true` if the [`Kind::String`] contains the `literal` or any element equals it
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn contains(input: Value, literal: Literal) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => Ok(Value::Bool(literal.is_in(&string))),
+        Value::StringVec(vec) => Ok(Value::Bool(vec.iter().any(|string| literal.is_eq(string)))),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Removes all but the first occurence of equal [`char`]s or elements of the given input,
+/// optionally ignoring case.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the input without duplicates
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn dedupe(input: Value, ignore_case: bool) -> Result<Value, Error> {
+    let mut seen = HashSet::new();
+    let mut is_new = |string: &str| {
+        if ignore_case {
+            seen.insert(helper::lowercase(string).collect::<String>())
+        } else {
+            seen.insert(string.to_owned())
+        }
+    };
+
+    match input {
+        Value::String(string) => Ok(Value::String(
+            string
+                .chars()
+                .filter(|ch| is_new(ch.encode_utf8(&mut [0; 4])))
+                .collect(),
+        )),
+        Value::StringVec(vec) => Ok(Value::StringVec(
+            vec.into_iter().filter(|string| is_new(string)).collect(),
+        )),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __find(
+    input: Value,
+    string_find: impl Fn(&str) -> Option<usize>,
+    vec_find: impl Fn(&[String]) -> Option<usize>,
+) -> Result<Value, Error> {
+    let found = match input {
+        Value::String(string) => Ok(string_find(&string)),
+        Value::StringVec(vec) => Ok(vec_find(&vec)),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }?;
+
+    Ok(match found {
+        Some(idx) => Value::String(idx.to_string()),
+        None => Value::Bool(false),
+    })
+}
+
+///
+/// Extracts the element at the given `index`.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the element at the given index
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn index(input: Value, index: EolinaIndex) -> Result<Value, Error> {
+    let len = match &input {
+        Value::String(inner) => Ok(inner.len()),
+        Value::StringVec(inner) => Ok(inner.len()),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }?;
+
+    let index = index.as_usize(len)?;
+
+    match input {
+        Value::String(string) => Ok(Value::String(
+            String::from_utf8_lossy(&string.as_bytes()[index..=index]).into_owned(),
+        )),
+        Value::StringVec(vec) => Ok(Value::String(vec[index].to_owned())),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Slices the given input at the lower and upper bounds.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the subslice of the input
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn slice(input: Value, range: EolinaRange) -> Result<Value, Error> {
+    let len = match &input {
+        Value::String(inner) => Ok(inner.len()),
+        Value::StringVec(inner) => Ok(inner.len()),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }?;
+
+    let range = range.as_range(len)?;
+
+    match input {
+        Value::String(string) => Ok(Value::String(string[range].to_owned())),
+        Value::StringVec(vec) => Ok(Value::StringVec(vec[range].to_owned())),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+///
+/// Inserts the given value into the target before the given `index`, an index equal to the
+/// target's length appends the value. A [`Kind::StringVec`] value is inserted element wise.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the target with the inserted value
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch, index or char boundary [`Error`]
+///
+pub fn insert(target: Value, value: Value, index: EolinaIndex) -> Result<Value, Error> {
+    let index = index.as_usize(__len(&target)?)?;
+    __splice(target, Some(value), index..index)
+}
+
+///
+/// Removes the element or [`char`] at the given `index`.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the input without the element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch, index or char boundary [`Error`]
+///
+pub fn remove(input: Value, index: EolinaIndex) -> Result<Value, Error> {
+    let range = __index_range(&input, index)?;
+    __splice(input, None, range)
+}
+
+///
+/// Removes the elements within the lower and upper bounds.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`]
+/// * [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the input without the elements
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch, range or char boundary [`Error`]
+///
+pub fn remove_slice(input: Value, range: EolinaRange) -> Result<Value, Error> {
+    let range = range.as_range(__len(&input)?)?;
+    __splice(input, None, range)
+}
+
+///
+/// Replaces the element or [`char`] at the given `index` of the target with the given value. A
+/// [`Kind::StringVec`] value replaces the element with all of it's elements.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the target with the replaced element
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch, index or char boundary [`Error`]
+///
+pub fn replace(target: Value, value: Value, index: EolinaIndex) -> Result<Value, Error> {
+    let range = __index_range(&target, index)?;
+    __splice(target, Some(value), range)
+}
+
+///
+/// Replaces the elements of the target within the lower and upper bounds with the given value.
+/// A [`Kind::StringVec`] value replaces them with all of it's elements.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::String`]
+/// * [`Kind::StringVec`] and [`Kind::StringVec`]
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the target with the replaced elements
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch, range or char boundary [`Error`]
+///
+pub fn replace_slice(target: Value, value: Value, range: EolinaRange) -> Result<Value, Error> {
+    let range = range.as_range(__len(&target)?)?;
+    __splice(target, Some(value), range)
+}
+
+fn __len(input: &Value) -> Result<usize, Error> {
+    match input {
+        Value::String(inner) => Ok(inner.len()),
+        Value::StringVec(inner) => Ok(inner.len()),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __index_range(input: &Value, index: EolinaIndex) -> Result<Range<usize>, Error> {
+    let len = __len(input)?;
+    let start = index.as_usize(len)?;
+
+    // unlike for inserting, the length itself is not a valid index
+    if start == len {
+        return Err(IndexError::OutOfTargetRange(index, start as isize, len).into());
+    }
+
+    match input {
+        Value::String(string) => {
+            if !string.is_char_boundary(start) {
+                return Err(Error::NotCharBoundary(start));
+            }
+
+            let ch = string[start..]
+                .chars()
+                .next()
+                .expect("start is before the end");
+            Ok(start..start + ch.len_utf8())
+        }
+        _ => Ok(start..start + 1),
+    }
+}
+
+fn __splice(target: Value, value: Option<Value>, range: Range<usize>) -> Result<Value, Error> {
+    match (target, value) {
+        (Value::String(mut string), value @ (None | Some(Value::String(_)))) => {
+            for bound in [range.start, range.end] {
+                if !string.is_char_boundary(bound) {
+                    return Err(Error::NotCharBoundary(bound));
+                }
+            }
+
+            let value = match value {
+                Some(Value::String(value)) => value,
+                _ => String::new(),
+            };
+
+            string.replace_range(range, &value);
+            Ok(Value::String(string))
+        }
+        (Value::StringVec(mut vec), None) => {
+            vec.drain(range);
+            Ok(Value::StringVec(vec))
+        }
+        (Value::StringVec(mut vec), Some(Value::String(value))) => {
+            vec.splice(range, [value]);
+            Ok(Value::StringVec(vec))
+        }
+        (Value::StringVec(mut vec), Some(Value::StringVec(values))) => {
+            vec.splice(range, values);
+            Ok(Value::StringVec(vec))
+        }
+        (Value::String(_), Some(x)) => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String],
+            x.kind(),
+        ))),
+        (Value::StringVec(_), Some(x)) => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+        (x, _) => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::helper::{DecodeError, Encoding, Format, UnescapeError};
+
+    #[test]
+    fn is_glob() {
+        let glob = Glob::parse("src/**/*.rs").unwrap();
+
+        assert_eq!(
+            super::is_glob(Value::String("src/main.rs".to_owned()), &glob).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::is_glob(
+                Value::StringVec(vec!["src/a/b.rs".to_owned(), "b.rs".to_owned()]),
+                &glob
+            )
+            .unwrap(),
+            Value::Bool(false)
+        );
+        assert!(super::is_glob(Value::Bool(true), &glob).is_err());
+    }
+
+    #[test]
+    fn filter_glob() {
+        let glob = Glob::parse("*.[ch]").unwrap();
+
+        assert_eq!(
+            super::filter_glob(
+                Value::StringVec(vec![
+                    "main.c".to_owned(),
+                    "main.o".to_owned(),
+                    "lib/util.h".to_owned(),
+                    "util.h".to_owned()
+                ]),
+                &glob
+            )
+            .unwrap(),
+            Value::StringVec(vec!["main.c".to_owned(), "util.h".to_owned()])
+        );
+        assert!(super::filter_glob(Value::String("a.c".to_owned()), &glob).is_err());
+    }
+
+    #[test]
+    fn ignore_case() {
+        let caseless = |text| Literal::new(text, true);
+
+        assert_eq!(
+            super::split(Value::String("aXbxc".to_owned()), Some(caseless("x"))).unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert_eq!(
+            super::count_literal(Value::String("AbaBab".to_owned()), caseless("ab")).unwrap(),
+            Value::String("3".to_owned())
+        );
+        assert_eq!(
+            super::find(
+                Value::StringVec(vec!["a".to_owned(), "Straße".to_owned()]),
+                caseless("STRASSE")
+            )
+            .unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            super::rfind(Value::String("ÄäÄ".to_owned()), caseless("ä")).unwrap(),
+            Value::String("4".to_owned())
+        );
+    }
+
+    #[test]
+    fn equals() {
+        assert_eq!(
+            super::equals(Value::String("Ab".to_owned()), "ab".into()).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            super::equals(Value::String("Ab".to_owned()), Literal::new("ab", true)).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::equals(
+                Value::StringVec(vec!["A".to_owned(), "a".to_owned()]),
+                Literal::new("a", true)
+            )
+            .unwrap(),
+            Value::Bool(true)
+        );
+        assert!(super::equals(Value::Bool(true), "a".into()).is_err());
+    }
+
+    #[test]
+    fn contains() {
+        assert_eq!(
+            super::contains(Value::String("xABy".to_owned()), "ab".into()).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            super::contains(Value::String("xABy".to_owned()), Literal::new("ab", true)).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::contains(
+                Value::StringVec(vec!["xab".to_owned(), "AB".to_owned()]),
+                Literal::new("ab", true)
+            )
+            .unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::contains(Value::StringVec(vec!["xab".to_owned()]), "ab".into()).unwrap(),
+            Value::Bool(false)
+        );
+    }
+
+    #[test]
+    fn dedupe() {
+        let vec = || {
+            Value::StringVec(vec![
+                "b".to_owned(),
+                "A".to_owned(),
+                "a".to_owned(),
+                "b".to_owned(),
+            ])
+        };
+
+        assert_eq!(
+            super::dedupe(vec(), false).unwrap(),
+            Value::StringVec(vec!["b".to_owned(), "A".to_owned(), "a".to_owned()])
+        );
+        assert_eq!(
+            super::dedupe(vec(), true).unwrap(),
+            Value::StringVec(vec!["b".to_owned(), "A".to_owned()])
+        );
+        assert_eq!(
+            super::dedupe(Value::String("aAbäÄ".to_owned()), true).unwrap(),
+            Value::String("abä".to_owned())
+        );
+        assert!(super::dedupe(Value::Bool(true), true).is_err());
+    }
+
+    #[test]
+    fn insert() {
+        let string = |string: &str| Value::String(string.to_owned());
+        let vec = || Value::StringVec(vec!["a".to_owned(), "b".to_owned()]);
+
+        assert_eq!(
+            super::insert(string("ac"), string("b"), 1usize.into()).unwrap(),
+            string("abc")
+        );
+        assert_eq!(
+            super::insert(string("ab"), string("c"), EolinaIndex::End(0)).unwrap(),
+            string("abc")
+        );
+        assert_eq!(
+            super::insert(vec(), string("x"), (-1isize).into()).unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "x".to_owned(), "b".to_owned()])
+        );
+        assert_eq!(
+            super::insert(vec(), vec(), 2usize.into()).unwrap(),
+            Value::StringVec(vec![
+                "a".to_owned(),
+                "b".to_owned(),
+                "a".to_owned(),
+                "b".to_owned()
+            ])
+        );
+        assert!(matches!(
+            super::insert(vec(), string("x"), 3usize.into()),
+            Err(Error::Index(_))
+        ));
+        assert!(matches!(
+            super::insert(string("äb"), string("x"), 1usize.into()),
+            Err(Error::NotCharBoundary(1))
+        ));
+        assert!(matches!(
+            super::insert(string("ab"), vec(), 1usize.into()),
+            Err(Error::ArgMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn remove() {
+        let string = |string: &str| Value::String(string.to_owned());
+        let vec = || Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]);
+
+        assert_eq!(
+            super::remove(string("aäb"), 1usize.into()).unwrap(),
+            string("ab")
+        );
+        assert_eq!(
+            super::remove(vec(), (-1isize).into()).unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "b".to_owned()])
+        );
+        assert!(matches!(
+            super::remove(vec(), 3usize.into()),
+            Err(Error::Index(_))
+        ));
+        assert!(matches!(
+            super::remove(string("äb"), 1usize.into()),
+            Err(Error::NotCharBoundary(1))
+        ));
+
+        assert_eq!(
+            super::remove_slice(string("abcd"), (1..3usize).into()).unwrap(),
+            string("ad")
+        );
+        assert_eq!(
+            super::remove_slice(vec(), (..2usize).into()).unwrap(),
+            Value::StringVec(vec!["c".to_owned()])
+        );
+        assert!(matches!(
+            super::remove_slice(vec(), (2..1usize).into()),
+            Err(Error::Range(_))
+        ));
+    }
+
+    #[test]
+    fn replace() {
+        let string = |string: &str| Value::String(string.to_owned());
+        let vec = || Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]);
+
+        assert_eq!(
+            super::replace(string("aäb"), string("x"), 1usize.into()).unwrap(),
+            string("axb")
+        );
+        assert_eq!(
+            super::replace(vec(), string("x"), (-3isize).into()).unwrap(),
+            Value::StringVec(vec!["x".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert!(matches!(
+            super::replace(vec(), string("x"), EolinaIndex::End(0)),
+            Err(Error::Index(_))
+        ));
+
+        assert_eq!(
+            super::replace_slice(string("abcd"), string("xyz"), (1..3usize).into()).unwrap(),
+            string("axyzd")
+        );
+        assert_eq!(
+            super::replace_slice(
+                vec(),
+                Value::StringVec(vec!["x".to_owned(), "y".to_owned()]),
+                (1usize..).into()
+            )
+            .unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "x".to_owned(), "y".to_owned()])
+        );
+        assert!(matches!(
+            super::replace_slice(Value::Bool(true), string("x"), (..).into()),
+            Err(Error::ArgMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn read_string() {
+        for (raw, expected) in [
+            ("", ""),
+            ("a", "a"),
+            ("a\n", "a"),
+            ("a\r\nb\r\n", "a\nb"),
+            ("a\nb", "a\nb"),
+            ("a\n\n", "a\n"),
+            ("\r\n\r\n", "\n"),
+        ] {
+            assert_eq!(
+                super::read_string(raw),
+                Value::String(expected.to_owned()),
+                "{:?}",
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn read_lines() {
+        assert_eq!(super::read_lines(""), Value::StringVec(vec![]));
+        assert_eq!(
+            super::read_lines("a\r\nb\nc"),
+            Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert_eq!(
+            super::read_lines("a\n\nb\r\n"),
+            Value::StringVec(vec!["a".to_owned(), "".to_owned(), "b".to_owned()])
+        );
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            super::split(Value::String("Abc".to_owned()), None).unwrap(),
+            Value::StringVec(vec!["A".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert_eq!(
+            super::split(Value::String("Abcbdebfb".to_owned()), Some("b".into())).unwrap(),
+            Value::StringVec(vec![
+                "A".to_owned(),
+                "c".to_owned(),
+                "de".to_owned(),
+                "f".to_owned()
+            ])
+        );
+        assert_eq!(
+            super::split(
+                Value::StringVec(vec!["a b".to_owned(), "c".to_owned()]),
+                Some(" ".into())
+            )
+            .unwrap(),
+            Value::StringVecVec(vec![
+                vec!["a".to_owned(), "b".to_owned()],
+                vec!["c".to_owned()]
+            ])
+        );
+        assert!(super::split(Value::Bool(true), None).is_err());
+    }
+
+    #[test]
+    fn join() {
+        assert_eq!(
+            super::join(Value::StringVec(vec![
+                "A".to_owned(),
+                "b".to_owned(),
+                "c".to_owned()
+            ]))
+            .unwrap(),
+            Value::String("Abc".to_owned())
+        );
+        assert_eq!(
+            super::join(Value::StringVecVec(vec![
+                vec!["a".to_owned(), "b".to_owned()],
+                vec![]
+            ]))
+            .unwrap(),
+            Value::StringVec(vec!["ab".to_owned(), "".to_owned()])
+        );
+        assert!(super::join(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn concat() {
+        assert_eq!(
+            super::concat(
+                Value::String("ab".to_owned()),
+                Value::String("c".to_owned())
+            )
+            .unwrap(),
+            Value::String("abc".to_owned())
+        );
+        assert_eq!(
+            super::concat(
+                Value::StringVecVec(vec![vec!["a".to_owned()]]),
+                Value::StringVecVec(vec![vec![], vec!["b".to_owned(), "c".to_owned()]])
+            )
+            .unwrap(),
+            Value::StringVecVec(vec![
+                vec!["a".to_owned()],
+                vec![],
+                vec!["b".to_owned(), "c".to_owned()]
+            ])
+        );
+        assert!(matches!(
+            super::concat(Value::StringVec(vec![]), Value::StringVecVec(vec![])),
+            Err(Error::Mismatch(Kind::StringVec, Kind::StringVecVec))
+        ));
+        assert!(super::concat(Value::Bool(true), Value::String("a".to_owned())).is_err());
+    }
+
+    #[test]
+    fn similarity() {
+        assert_eq!(
+            super::similarity(
+                Value::String("kitten".to_owned()),
+                Value::String("sitting".to_owned()),
+                MetricToken::Levenshtein,
+                1024
+            )
+            .unwrap(),
+            Value::String("3".to_owned())
+        );
+        assert_eq!(
+            super::similarity(
+                Value::String("ca".to_owned()),
+                Value::String("ac".to_owned()),
+                MetricToken::Damerau,
+                1024
+            )
+            .unwrap(),
+            Value::String("1".to_owned())
+        );
+        assert_eq!(
+            super::similarity(
+                Value::String("martha".to_owned()),
+                Value::String("marhta".to_owned()),
+                MetricToken::JaroWinkler,
+                1024
+            )
+            .unwrap(),
+            Value::String("0.9611".to_owned())
+        );
+        assert!(super::similarity(
+            Value::String("a".to_owned()),
+            Value::Bool(true),
+            MetricToken::Levenshtein,
+            1024
+        )
+        .is_err());
+        assert!(matches!(
+            super::similarity(
+                Value::String("a".repeat(100)),
+                Value::String("b".repeat(100)),
+                MetricToken::Damerau,
+                1024
+            ),
+            Err(Error::TooLarge(_, 1024))
+        ));
+        assert!(super::similarity(
+            Value::String("a".repeat(100)),
+            Value::String("b".repeat(100)),
+            MetricToken::Levenshtein,
+            1024
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn diff() {
+        assert_eq!(
+            super::diff(
+                Value::String("a\nb".to_owned()),
+                Value::String("a\nc".to_owned()),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec![
+                "@@ -1,2 +1,2 @@".to_owned(),
+                " a".to_owned(),
+                "-b".to_owned(),
+                "+c".to_owned()
+            ])
+        );
+        assert!(super::diff(
+            Value::StringVec(vec![]),
+            Value::String("a".to_owned()),
+            1024
+        )
+        .is_err());
+        assert!(matches!(
+            super::diff(
+                Value::String("a\n".repeat(100)),
+                Value::String("b\n".repeat(100)),
+                1024
+            ),
+            Err(Error::TooLarge(_, 1024))
+        ));
+    }
+
+    #[test]
+    fn is_conso() {
+        assert_eq!(
+            super::is_conso(Value::String("bcd".to_owned())).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::is_conso(Value::String("aei".to_owned())).unwrap(),
+            Value::Bool(false)
+        );
+        assert!(super::is_conso(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn is_vowel() {
+        assert_eq!(
+            super::is_vowel(Value::String("bcd".to_owned())).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            super::is_vowel(Value::String("aei".to_owned())).unwrap(),
+            Value::Bool(true)
+        );
+        assert!(super::is_vowel(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn is_lower() {
+        assert_eq!(
+            super::is_lower(Value::String("ABC".to_owned())).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            super::is_lower(Value::String("abc".to_owned())).unwrap(),
+            Value::Bool(true)
+        );
+        assert!(super::is_lower(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn is_upper() {
+        assert_eq!(
+            super::is_upper(Value::String("ABC".to_owned())).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            super::is_upper(Value::String("abc".to_owned())).unwrap(),
+            Value::Bool(false)
+        );
+        assert!(super::is_upper(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn map() {
+        assert_eq!(
+            super::map(Value::String("aBc".to_owned()), MapToken::Lower, 1024).unwrap(),
+            Value::String("abc".to_owned())
+        );
+        assert_eq!(
+            super::map(Value::String("abC".to_owned()), MapToken::Upper, 1024).unwrap(),
+            Value::String("ABC".to_owned())
+        );
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["AbC".to_owned(), "dEf".to_owned()]),
+                MapToken::Swap,
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["aBc".to_owned(), "DeF".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["user id".to_owned(), "Max Value".to_owned()]),
+                MapToken::Snake,
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["user_id".to_owned(), "max_value".to_owned()])
+        );
+        assert_eq!(
+            super::map(Value::String("the title".to_owned()), MapToken::Title, 1024).unwrap(),
+            Value::String("The Title".to_owned())
+        );
+        assert!(super::map(Value::Bool(true), MapToken::Lower, 1024).is_err());
+    }
+
+    #[test]
+    fn map_encoding() {
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["a b".to_owned(), "c&d".to_owned()]),
+                MapToken::Encode(Encoding::Percent),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["a%20b".to_owned(), "c%26d".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("Zm9vYmFy".to_owned()),
+                MapToken::Decode(Encoding::Base64),
+                1024
+            )
+            .unwrap(),
+            Value::String("foobar".to_owned())
+        );
+        assert!(matches!(
+            super::map(
+                Value::StringVec(vec!["6162".to_owned(), "61x2".to_owned()]),
+                MapToken::Decode(Encoding::Hex),
+                1024
+            ),
+            Err(Error::Decode(DecodeError::InvalidInput(Encoding::Hex, 2)))
+        ));
+    }
+
+    #[test]
+    fn map_escape() {
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["a\"b".to_owned(), "c".to_owned()]),
+                MapToken::Escape(Format::Json),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["\"a\\\"b\"".to_owned(), "\"c\"".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("&lt;b&gt;".to_owned()),
+                MapToken::Unescape(Format::Html),
+                1024
+            )
+            .unwrap(),
+            Value::String("<b>".to_owned())
+        );
+        assert!(matches!(
+            super::map(
+                Value::String("'abc".to_owned()),
+                MapToken::Unescape(Format::Shell),
+                1024
+            ),
+            Err(Error::Unescape(UnescapeError::InvalidInput(
+                Format::Shell,
+                0
+            )))
+        ));
+    }
+
+    #[test]
+    fn map_cipher() {
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["Hello".to_owned(), "World!".to_owned()]),
+                MapToken::Rotate(13),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["Uryyb".to_owned(), "Jbeyq!".to_owned()])
+        );
+        assert_eq!(
+            super::map(Value::String("Zap".to_owned()), MapToken::Rotate(-1), 1024).unwrap(),
+            Value::String("Yzo".to_owned())
+        );
+        assert_eq!(
+            super::map(Value::String("Zap".to_owned()), MapToken::Atbash, 1024).unwrap(),
+            Value::String("Azk".to_owned())
+        );
+    }
+
+    #[test]
+    fn map_code() {
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["a".to_owned(), "é".to_owned()]),
+                MapToken::Ord(RadixToken::Hex),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["61".to_owned(), "e9".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("1000001".to_owned()),
+                MapToken::Chr(RadixToken::Bin),
+                1024
+            )
+            .unwrap(),
+            Value::String("A".to_owned())
+        );
+        assert!(matches!(
+            super::map(
+                Value::String("ab".to_owned()),
+                MapToken::Ord(RadixToken::Dec), 1024
+            ),
+            Err(Error::NotChar(string)) if string == "ab"
+        ));
+        assert!(matches!(
+            super::map(
+                Value::String("d800".to_owned()),
+                MapToken::Chr(RadixToken::Hex), 1024
+            ),
+            Err(Error::CharCode(string)) if string == "d800"
+        ));
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(
+            super::codes(Value::String("Hi!".to_owned()), RadixToken::Dec).unwrap(),
+            Value::StringVec(vec!["72".to_owned(), "105".to_owned(), "33".to_owned()])
+        );
+        assert_eq!(
+            super::codes(Value::String("".to_owned()), RadixToken::Bin).unwrap(),
+            Value::StringVec(vec![])
+        );
+        assert!(super::codes(Value::Bool(true), RadixToken::Dec).is_err());
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(
+            super::chars(
+                Value::StringVec(vec!["48".to_owned(), "1f980".to_owned()]),
+                RadixToken::Hex
+            )
+            .unwrap(),
+            Value::String("H🦀".to_owned())
+        );
+        assert!(matches!(
+            super::chars(
+                Value::StringVec(vec!["72".to_owned(), "x".to_owned()]),
+                RadixToken::Dec
+            ),
+            Err(Error::CharCode(string)) if string == "x"
+        ));
+        assert!(super::chars(Value::String("72".to_owned()), RadixToken::Dec).is_err());
+    }
+
+    #[test]
+    fn map_unicode() {
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["e\u{301}".to_owned(), "é".to_owned()]),
+                MapToken::Nfc,
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["é".to_owned(), "é".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("Crème".to_owned()),
+                MapToken::NoDiacritics,
+                1024
+            )
+            .unwrap(),
+            Value::String("Creme".to_owned())
+        );
+        assert_eq!(
+            super::map(Value::String("Straße".to_owned()), MapToken::Ascii, 1024).unwrap(),
+            Value::String("Strasse".to_owned())
+        );
+    }
+
+    #[test]
+    fn map_trim() {
+        assert_eq!(
+            super::map(
+                Value::String(" \tab c \n".to_owned()),
+                MapToken::Trim(None),
+                1024
+            )
+            .unwrap(),
+            Value::String("ab c".to_owned())
+        );
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["  a ".to_owned(), "b  ".to_owned()]),
+                MapToken::TrimStart(None),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["a ".to_owned(), "b  ".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("--a-b-+".to_owned()),
+                MapToken::TrimEnd(Some("+-")),
+                1024
+            )
+            .unwrap(),
+            Value::String("--a-b".to_owned())
+        );
+    }
+
+    #[test]
+    fn map_pad() {
+        assert_eq!(
+            super::map(
+                Value::String("ab".to_owned()),
+                MapToken::PadLeft(5, '0'),
+                1024
+            )
+            .unwrap(),
+            Value::String("000ab".to_owned())
+        );
+        assert_eq!(
+            super::map(
+                Value::StringVec(vec!["ab".to_owned(), "abcdef".to_owned()]),
+                MapToken::PadRight(4, '.'),
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["ab..".to_owned(), "abcdef".to_owned()])
+        );
+        assert_eq!(
+            super::map(
+                Value::String("ab".to_owned()),
+                MapToken::PadCenter(5, ' '),
+                1024
+            )
+            .unwrap(),
+            Value::String(" ab  ".to_owned())
+        );
+        assert!(matches!(
+            super::map(
+                Value::String("ab".to_owned()),
+                MapToken::PadLeft(usize::MAX, 'ä'),
+                1024
+            ),
+            Err(Error::TooLarge(usize::MAX, 1024))
+        ));
+        assert!(matches!(
+            super::map(
+                Value::String("ab".to_owned()),
+                MapToken::PadCenter(1025, ' '),
+                1024
+            ),
+            Err(Error::TooLarge(1025, 1024))
+        ));
+    }
+
+    #[test]
+    fn map_truncate() {
+        assert_eq!(
+            super::map(
+                Value::String("abcdefgh".to_owned()),
+                MapToken::Truncate(6),
+                1024
+            )
+            .unwrap(),
+            Value::String("abc...".to_owned())
+        );
+        assert_eq!(
+            super::map(
+                Value::String("abcdef".to_owned()),
+                MapToken::Truncate(6),
+                1024
+            )
+            .unwrap(),
+            Value::String("abcdef".to_owned())
+        );
+        assert_eq!(
+            super::map(
+                Value::String("abcdef".to_owned()),
+                MapToken::Truncate(2),
+                1024
+            )
+            .unwrap(),
+            Value::String("..".to_owned())
+        );
     }
 
     #[test]
-    fn join() {
+    fn filter() {
         assert_eq!(
-            super::join(Value::StringVec(vec![
-                "A".to_owned(),
+            super::filter(Value::String("abC".to_owned()), CheckToken::Vowel).unwrap(),
+            Value::String("a".to_owned())
+        );
+        assert_eq!(
+            super::filter(Value::String("aBc".to_owned()), CheckToken::Conso).unwrap(),
+            Value::String("Bc".to_owned())
+        );
+        assert_eq!(
+            super::filter(
+                Value::StringVec(vec!["ABC".to_owned(), "def".to_owned()]),
+                CheckToken::Upper
+            )
+            .unwrap(),
+            Value::StringVec(vec!["ABC".to_owned()])
+        );
+        assert_eq!(
+            super::filter(
+                Value::StringVec(vec!["abc".to_owned(), "DEF".to_owned()]),
+                CheckToken::Lower
+            )
+            .unwrap(),
+            Value::StringVec(vec!["abc".to_owned()])
+        );
+        assert!(super::filter(Value::Bool(true), CheckToken::Vowel).is_err());
+    }
+
+    #[test]
+    fn partition() {
+        assert_eq!(
+            super::partition(Value::String("aBcD".to_owned()), CheckToken::Upper).unwrap(),
+            [
+                Value::String("BD".to_owned()),
+                Value::String("ac".to_owned())
+            ]
+        );
+        assert_eq!(
+            super::partition(
+                Value::StringVec(vec!["ae".to_owned(), "bc".to_owned(), "io".to_owned()]),
+                CheckToken::Vowel
+            )
+            .unwrap(),
+            [
+                Value::StringVec(vec!["ae".to_owned(), "io".to_owned()]),
+                Value::StringVec(vec!["bc".to_owned()])
+            ]
+        );
+        assert!(super::partition(Value::Bool(true), CheckToken::Vowel).is_err());
+    }
+
+    #[test]
+    fn repeat() {
+        assert_eq!(
+            super::repeat(Value::String("ab".to_owned()), 3, 6).unwrap(),
+            Value::String("ababab".to_owned())
+        );
+        assert_eq!(
+            super::repeat(Value::String("ab".to_owned()), 0, 0).unwrap(),
+            Value::String("".to_owned())
+        );
+        assert_eq!(
+            super::repeat(Value::StringVec(vec!["a".to_owned(), "b".to_owned()]), 2, 8).unwrap(),
+            Value::StringVec(vec![
+                "a".to_owned(),
                 "b".to_owned(),
-                "c".to_owned()
-            ]))
+                "a".to_owned(),
+                "b".to_owned()
+            ])
+        );
+        assert!(matches!(
+            super::repeat(Value::String("ab".to_owned()), 4, 7),
+            Err(Error::TooLarge(8, 7))
+        ));
+        assert!(matches!(
+            super::repeat(Value::StringVec(vec!["".to_owned()]), usize::MAX, 1024),
+            Err(Error::TooLarge(usize::MAX, 1024))
+        ));
+        assert!(super::repeat(Value::Bool(true), 1, 1024).is_err());
+    }
+
+    #[test]
+    fn repeat_by() {
+        assert_eq!(
+            super::repeat_by(
+                Value::String("-".to_owned()),
+                Value::String("3".to_owned()),
+                1024
+            )
             .unwrap(),
-            Value::String("Abc".to_owned())
+            Value::String("---".to_owned())
         );
-        assert!(super::join(Value::Bool(true)).is_err());
+        assert!(matches!(
+            super::repeat_by(
+                Value::String("-".to_owned()),
+                Value::String("-3".to_owned()),
+                1024
+            ),
+            Err(Error::NotCount(string)) if string == "-3"
+        ));
+        assert!(super::repeat_by(Value::String("-".to_owned()), Value::Bool(true), 1024).is_err());
     }
 
     #[test]
-    fn is_conso() {
+    fn map_run_length() {
+        let encode = |input: &str| {
+            super::map(Value::String(input.to_owned()), MapToken::RunLength, 1024).unwrap()
+        };
+        let decode = |input: &str| {
+            super::map(
+                Value::String(input.to_owned()),
+                MapToken::RunLengthDecode,
+                1024,
+            )
+        };
+
+        assert_eq!(encode("aaab"), Value::String("3a1b".to_owned()));
         assert_eq!(
-            super::is_conso(Value::String("bcd".to_owned())).unwrap(),
-            Value::Bool(true)
+            encode("1112\\\\"),
+            Value::String("3\\11\\22\\\\".to_owned())
         );
+        assert_eq!(encode("ääx"), Value::String("2ä1x".to_owned()));
+        assert_eq!(encode(""), Value::String("".to_owned()));
+
+        for input in ["aaab", "1112\\\\", "ääx", "", "a 22\\3 ", "zzzzzzzzzzzz"] {
+            assert_eq!(
+                decode(&encode(input).to_string()).unwrap(),
+                Value::String(input.to_owned())
+            );
+        }
+
         assert_eq!(
-            super::is_conso(Value::String("aei".to_owned())).unwrap(),
-            Value::Bool(false)
+            decode("12x").unwrap(),
+            Value::String("xxxxxxxxxxxx".to_owned())
         );
-        assert!(super::is_conso(Value::Bool(true)).is_err());
+        assert!(matches!(decode("a"), Err(Error::RunLength(_))));
+        assert!(matches!(decode("3"), Err(Error::RunLength(_))));
+        assert!(matches!(decode("2\\"), Err(Error::RunLength(_))));
+        assert!(matches!(decode("2000a"), Err(Error::TooLarge(2000, 1024))));
+        assert!(matches!(
+            decode("99999999999999999999999a"),
+            Err(Error::RunLength(_))
+        ));
     }
 
     #[test]
-    fn is_vowel() {
+    fn select() {
+        let vec = || {
+            Value::StringVec(vec![
+                "bb".to_owned(),
+                "a".to_owned(),
+                "ccc".to_owned(),
+                "äö".to_owned(),
+                "c".to_owned(),
+                "ddd".to_owned(),
+            ])
+        };
+
+        for (extremum, element, idx) in [
+            (ExtremumToken::Min, "a", "1"),
+            (ExtremumToken::Max, "äö", "3"),
+            (ExtremumToken::Shortest, "a", "1"),
+            (ExtremumToken::Longest, "ccc", "2"),
+        ] {
+            assert_eq!(
+                super::select(vec(), extremum).unwrap(),
+                Value::String(element.to_owned())
+            );
+            assert_eq!(
+                super::select_index(vec(), extremum).unwrap(),
+                Value::String(idx.to_owned())
+            );
+        }
+
+        assert!(matches!(
+            super::select(Value::StringVec(vec![]), ExtremumToken::Min),
+            Err(Error::Empty(Kind::StringVec))
+        ));
+        assert!(matches!(
+            super::select_index(Value::String("a".to_owned()), ExtremumToken::Max),
+            Err(Error::ArgMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn group() {
         assert_eq!(
-            super::is_vowel(Value::String("bcd".to_owned())).unwrap(),
-            Value::Bool(false)
+            super::group(Value::String("aaba".to_owned())).unwrap(),
+            Value::StringVec(vec!["aa".to_owned(), "b".to_owned(), "a".to_owned()])
         );
         assert_eq!(
-            super::is_vowel(Value::String("aei".to_owned())).unwrap(),
-            Value::Bool(true)
+            super::group(Value::StringVec(vec![
+                "x".to_owned(),
+                "x".to_owned(),
+                "y".to_owned()
+            ]))
+            .unwrap(),
+            Value::StringVecVec(vec![
+                vec!["x".to_owned(), "x".to_owned()],
+                vec!["y".to_owned()]
+            ])
         );
-        assert!(super::is_vowel(Value::Bool(true)).is_err());
+        assert_eq!(
+            super::group(Value::StringVec(vec![])).unwrap(),
+            Value::StringVecVec(vec![])
+        );
+
+        // grouping and joining the rows round trips
+        let vec = Value::StringVec(vec!["a".to_owned(), "a".to_owned(), "b".to_owned()]);
+        let grouped = super::group(vec.clone()).unwrap();
+        assert_eq!(
+            super::concat(
+                Value::StringVec(vec![]),
+                super::cast(grouped, CastToken::List).unwrap()
+            )
+            .unwrap(),
+            vec
+        );
+        assert!(super::group(Value::Bool(true)).is_err());
     }
 
     #[test]
-    fn is_lower() {
+    fn cast() {
+        let string = |string: &str| Value::String(string.to_owned());
+        let vec = Value::StringVec(vec!["a".to_owned(), "b".to_owned()]);
+        let rows = Value::StringVecVec(vec![
+            vec!["a".to_owned(), "b".to_owned()],
+            vec!["c".to_owned()],
+        ]);
+
         assert_eq!(
-            super::is_lower(Value::String("ABC".to_owned())).unwrap(),
+            super::cast(vec.clone(), CastToken::String).unwrap(),
+            string("a b")
+        );
+        assert_eq!(
+            super::cast(rows.clone(), CastToken::String).unwrap(),
+            string("a b\nc")
+        );
+        assert_eq!(
+            super::cast(Value::Bool(true), CastToken::String).unwrap(),
+            string("true")
+        );
+
+        assert_eq!(
+            super::cast(string("a b"), CastToken::List).unwrap(),
+            Value::StringVec(vec!["a b".to_owned()])
+        );
+        assert_eq!(
+            super::cast(rows.clone(), CastToken::List).unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert_eq!(super::cast(vec.clone(), CastToken::List).unwrap(), vec);
+
+        for (input, expected) in [("", false), ("0", false), ("false", false), ("x", true)] {
+            assert_eq!(
+                super::cast(string(input), CastToken::Bool).unwrap(),
+                Value::Bool(expected)
+            );
+        }
+        assert_eq!(
+            super::cast(Value::StringVec(vec![]), CastToken::Bool).unwrap(),
             Value::Bool(false)
         );
         assert_eq!(
-            super::is_lower(Value::String("abc".to_owned())).unwrap(),
+            super::cast(vec.clone(), CastToken::Bool).unwrap(),
             Value::Bool(true)
         );
-        assert!(super::is_lower(Value::Bool(true)).is_err());
+
+        for (input, expected) in [
+            (" 042 ", "42"),
+            ("+7", "7"),
+            ("-1.50", "-1.5"),
+            ("1e3", "1000"),
+            (
+                "170141183460469231731687303715884105727",
+                "170141183460469231731687303715884105727",
+            ),
+        ] {
+            assert_eq!(
+                super::cast(string(input), CastToken::Number).unwrap(),
+                string(expected)
+            );
+        }
+        assert!(matches!(
+            super::cast(string("inf"), CastToken::Number),
+            Err(Error::NotNumber(_))
+        ));
+        assert!(matches!(
+            super::cast(vec.clone(), CastToken::Number),
+            Err(Error::NotNumber(_))
+        ));
+        assert!(matches!(
+            super::cast(Value::Bool(false), CastToken::Number),
+            Err(Error::ArgMismatch(_))
+        ));
+
+        assert_eq!(
+            super::cast(rows, CastToken::Kind).unwrap(),
+            string("StringVecVec")
+        );
     }
 
     #[test]
-    fn is_upper() {
+    fn template() {
+        let values = [
+            Value::String("a".to_owned()),
+            Value::StringVec(vec!["b".to_owned(), "c".to_owned()]),
+            Value::StringVecVec(vec![
+                vec!["d".to_owned(), "e".to_owned()],
+                vec!["f".to_owned()],
+            ]),
+        ];
+
+        let template = Template::parse("{}: {1} / {1:,} / {2:-}").unwrap();
         assert_eq!(
-            super::is_upper(Value::String("ABC".to_owned())).unwrap(),
-            Value::Bool(true)
+            super::template(&template, &values),
+            Value::String("a: b c / b,c / d-e\nf".to_owned())
+        );
+
+        let template = Template::parse("{{{0:,}}}").unwrap();
+        assert_eq!(
+            super::template(&template, &values),
+            Value::String("{a}".to_owned())
         );
+    }
+
+    #[test]
+    fn sequence() {
+        let strings =
+            |values: &[isize]| Value::StringVec(values.iter().map(ToString::to_string).collect());
+
         assert_eq!(
-            super::is_upper(Value::String("abc".to_owned())).unwrap(),
-            Value::Bool(false)
+            super::sequence(0, 5, 1, 1024).unwrap(),
+            strings(&[0, 1, 2, 3, 4])
         );
-        assert!(super::is_upper(Value::Bool(true)).is_err());
+        assert_eq!(
+            super::sequence(-3, 4, 3, 1024).unwrap(),
+            strings(&[-3, 0, 3])
+        );
+        assert_eq!(super::sequence(5, 0, 2, 1024).unwrap(), strings(&[5, 3, 1]));
+        assert_eq!(super::sequence(2, 2, 1, 1024).unwrap(), strings(&[]));
+        assert_eq!(
+            super::sequence(0, 10, usize::MAX, 1024).unwrap(),
+            strings(&[0])
+        );
+        assert_eq!(
+            super::sequence(isize::MAX, isize::MIN, 1 << 63, 1024).unwrap(),
+            strings(&[isize::MAX, -1])
+        );
+        assert!(matches!(
+            super::sequence(0, isize::MAX, 1, 1024),
+            Err(Error::TooLarge(_, 1024))
+        ));
     }
 
     #[test]
-    fn map() {
+    fn char_range() {
         assert_eq!(
-            super::map(Value::String("aBc".to_owned()), MapToken::Lower).unwrap(),
-            Value::String("abc".to_owned())
+            super::char_range('a', 'e', 1024).unwrap(),
+            Value::StringVec(vec![
+                "a".to_owned(),
+                "b".to_owned(),
+                "c".to_owned(),
+                "d".to_owned(),
+                "e".to_owned()
+            ])
         );
         assert_eq!(
-            super::map(Value::String("abC".to_owned()), MapToken::Upper).unwrap(),
-            Value::String("ABC".to_owned())
+            super::char_range('γ', 'α', 1024).unwrap(),
+            Value::StringVec(vec!["γ".to_owned(), "β".to_owned(), "α".to_owned()])
         );
         assert_eq!(
-            super::map(
-                Value::StringVec(vec!["AbC".to_owned(), "dEf".to_owned()]),
-                MapToken::Swap
+            super::char_range('\u{d7ff}', '\u{e000}', 1024).unwrap(),
+            Value::StringVec(vec!["\u{d7ff}".to_owned(), "\u{e000}".to_owned()])
+        );
+        assert!(matches!(
+            super::char_range('\0', char::MAX, 1024),
+            Err(Error::TooLarge(_, 1024))
+        ));
+    }
+
+    #[test]
+    fn choice() {
+        let rng = Rng::new(0);
+        let vec = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+
+        for _ in 0..10 {
+            match super::choice(Value::StringVec(vec.clone()), &rng).unwrap() {
+                Value::String(string) => assert!(vec.contains(&string)),
+                x => panic!("unexpected {:?}", x),
+            }
+        }
+
+        assert_eq!(
+            super::choice(Value::String("é".to_owned()), &rng).unwrap(),
+            Value::String("é".to_owned())
+        );
+        assert!(matches!(
+            super::choice(Value::StringVec(vec![]), &rng),
+            Err(Error::Empty(Kind::StringVec))
+        ));
+        assert!(super::choice(Value::Bool(true), &rng).is_err());
+    }
+
+    #[test]
+    fn sample() {
+        let vec = (0..10).map(|n| n.to_string()).collect::<Vec<_>>();
+
+        let picked = super::sample(Value::StringVec(vec.clone()), 4, &Rng::new(3)).unwrap();
+        match &picked {
+            Value::StringVec(picked) => {
+                assert_eq!(picked.len(), 4);
+                assert!(picked.iter().all(|string| vec.contains(string)));
+                assert!((1..4).all(|idx| !picked[idx..].contains(&picked[idx - 1])));
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+
+        // equal seeds pick equal samples
+        assert_eq!(
+            super::sample(Value::StringVec(vec), 4, &Rng::new(3)).unwrap(),
+            picked
+        );
+
+        match super::sample(Value::String("abc".to_owned()), 5, &Rng::new(3)).unwrap() {
+            Value::String(string) => {
+                let mut chars = string.chars().collect::<Vec<_>>();
+                chars.sort_unstable();
+                assert_eq!(chars, ['a', 'b', 'c']);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn shuffle() {
+        let vec = (0..10).map(|n| n.to_string()).collect::<Vec<_>>();
+
+        match super::shuffle(Value::StringVec(vec.clone()), &Rng::new(5)).unwrap() {
+            Value::StringVec(mut shuffled) => {
+                assert_ne!(shuffled, vec);
+                shuffled.sort_unstable_by_key(|string| string.parse::<u8>().unwrap());
+                assert_eq!(shuffled, vec);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+
+        assert!(super::shuffle(Value::Bool(true), &Rng::new(5)).is_err());
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(
+            super::count(Value::String("aBcDe".to_owned()), CheckToken::Upper).unwrap(),
+            Value::String("2".to_owned())
+        );
+        assert_eq!(
+            super::count(
+                Value::StringVec(vec!["ae".to_owned(), "bc".to_owned(), "io".to_owned()]),
+                CheckToken::Vowel
             )
             .unwrap(),
-            Value::StringVec(vec!["aBc".to_owned(), "DeF".to_owned()])
+            Value::String("2".to_owned())
         );
-        assert!(super::map(Value::Bool(true), MapToken::Lower).is_err());
+        assert!(super::count(Value::Bool(true), CheckToken::Vowel).is_err());
     }
 
     #[test]
-    fn filter() {
+    fn count_literal() {
         assert_eq!(
-            super::filter(Value::String("abC".to_owned()), CheckToken::Vowel).unwrap(),
-            Value::String("a".to_owned())
+            super::count_literal(Value::String("abababa".to_owned()), "aba".into()).unwrap(),
+            Value::String("2".to_owned())
         );
         assert_eq!(
-            super::filter(Value::String("aBc".to_owned()), CheckToken::Conso).unwrap(),
-            Value::String("Bc".to_owned())
+            super::count_literal(
+                Value::StringVec(vec!["ab".to_owned(), "abab".to_owned(), "ab".to_owned()]),
+                "ab".into()
+            )
+            .unwrap(),
+            Value::String("2".to_owned())
         );
+        assert!(super::count_literal(Value::Bool(true), "a".into()).is_err());
+    }
+
+    #[test]
+    fn frequency() {
         assert_eq!(
-            super::filter(
-                Value::StringVec(vec!["ABC".to_owned(), "def".to_owned()]),
-                CheckToken::Upper
+            super::frequency(Value::String("abcab".to_owned())).unwrap(),
+            Value::StringVec(vec!["a 2".to_owned(), "b 2".to_owned(), "c 1".to_owned()])
+        );
+        assert_eq!(
+            super::frequency(Value::StringVec(vec![
+                "warn".to_owned(),
+                "info".to_owned(),
+                "warn".to_owned(),
+                "error".to_owned(),
+                "warn".to_owned(),
+            ]))
+            .unwrap(),
+            Value::StringVec(vec![
+                "warn 3".to_owned(),
+                "error 1".to_owned(),
+                "info 1".to_owned()
+            ])
+        );
+        assert!(super::frequency(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn map_with() {
+        assert_eq!(
+            super::map_with(Value::String("aBc".to_owned()), |val| match val {
+                Value::String(inner) => Ok::<_, Error>(Value::String(inner.repeat(2))),
+                x => Ok(x),
+            })
+            .unwrap(),
+            Value::String("aaBBcc".to_owned())
+        );
+        assert_eq!(
+            super::map_with(
+                Value::StringVec(vec!["ab".to_owned(), "cd".to_owned()]),
+                |val| super::concat(val.clone(), val)
             )
             .unwrap(),
-            Value::StringVec(vec!["ABC".to_owned()])
+            Value::StringVec(vec!["abab".to_owned(), "cdcd".to_owned()])
         );
+        assert!(super::map_with(Value::String("abc".to_owned()), super::is_vowel).is_err());
+        assert!(super::map_with(Value::Bool(true), Ok::<_, Error>).is_err());
+    }
+
+    #[test]
+    fn filter_with() {
         assert_eq!(
-            super::filter(
-                Value::StringVec(vec!["abc".to_owned(), "DEF".to_owned()]),
-                CheckToken::Lower
+            super::filter_with(Value::String("aBc".to_owned()), super::is_lower).unwrap(),
+            Value::String("ac".to_owned())
+        );
+        assert_eq!(
+            super::filter_with(
+                Value::StringVec(vec!["ab".to_owned(), "ei".to_owned()]),
+                super::is_vowel
             )
             .unwrap(),
-            Value::StringVec(vec!["abc".to_owned()])
+            Value::StringVec(vec!["ei".to_owned()])
         );
-        assert!(super::filter(Value::Bool(true), CheckToken::Vowel).is_err());
+        assert!(super::filter_with(Value::String("abc".to_owned()), Ok::<_, Error>).is_err());
+        assert!(super::filter_with(Value::Bool(true), super::is_vowel).is_err());
+    }
+
+    #[test]
+    fn fold_with() {
+        assert_eq!(
+            super::fold_with(
+                Value::StringVec(vec!["ab".to_owned(), "cd".to_owned(), "ef".to_owned()]),
+                Value::String("-".to_owned()),
+                super::concat
+            )
+            .unwrap(),
+            Value::String("-abcdef".to_owned())
+        );
+        assert_eq!(
+            super::fold_with(
+                Value::String("abc".to_owned()),
+                Value::StringVec(vec![]),
+                |acc, val| super::concat(acc, super::split(val, None)?)
+            )
+            .unwrap(),
+            Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()])
+        );
+        assert_eq!(
+            super::fold_with(Value::StringVec(vec![]), Value::Bool(true), super::concat).unwrap(),
+            Value::Bool(true)
+        );
+        assert!(super::fold_with(
+            Value::String("abc".to_owned()),
+            Value::Bool(true),
+            super::concat
+        )
+        .is_err());
+        assert!(super::fold_with(Value::Bool(true), Value::Bool(true), super::concat).is_err());
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(
+            super::find(Value::String("abcabc".to_owned()), "bc".into()).unwrap(),
+            Value::String("1".to_owned())
+        );
+        assert_eq!(
+            super::find(
+                Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "b".to_owned()]),
+                "b".into()
+            )
+            .unwrap(),
+            Value::String("1".to_owned())
+        );
+        assert_eq!(
+            super::find(Value::String("abc".to_owned()), "d".into()).unwrap(),
+            Value::Bool(false)
+        );
+        assert!(super::find(Value::Bool(true), "a".into()).is_err());
+    }
+
+    #[test]
+    fn rfind() {
+        assert_eq!(
+            super::rfind(Value::String("abcabc".to_owned()), "bc".into()).unwrap(),
+            Value::String("4".to_owned())
+        );
+        assert_eq!(
+            super::rfind(
+                Value::StringVec(vec!["a".to_owned(), "b".to_owned(), "b".to_owned()]),
+                "b".into()
+            )
+            .unwrap(),
+            Value::String("2".to_owned())
+        );
+        assert_eq!(
+            super::rfind(Value::StringVec(vec![]), "a".into()).unwrap(),
+            Value::Bool(false)
+        );
+        assert!(super::rfind(Value::Bool(true), "a".into()).is_err());
     }
 
     #[test]
@@ -0,0 +1,473 @@
+use super::{ArgMismatchError, Error, Kind, Value};
+use std::mem;
+
+///
+/// Wraps every line of the given input at `width` [`char`]s on word boundaries. Whitespace
+/// between words is collapsed, the leading whitespace of a line is kept for all of it's rows and
+/// words longer than `width` are not broken.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`], lines are separated by `\n`
+/// * [`Kind::StringVec`], every element is a line
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the wrapped lines
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn wrap(input: Value, width: usize) -> Result<Value, Error> {
+    __layout(input, |lines| {
+        lines.iter().flat_map(|line| __wrap(line, width)).collect()
+    })
+}
+
+///
+/// Prefixes every non-empty line of the given input with `prefix` repeated `count` times.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`], lines are separated by `\n`
+/// * [`Kind::StringVec`], every element is a line
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the indented lines
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///   * `error` contains a too large [`Error`] if the output would exceed `max_size`
+///
+pub fn indent(input: Value, prefix: &str, count: usize, max_size: usize) -> Result<Value, Error> {
+    let (size, lines) = match &input {
+        Value::String(string) => (
+            string.len(),
+            string.split('\n').filter(|line| !line.is_empty()).count(),
+        ),
+        Value::StringVec(vec) => (
+            vec.iter().map(String::len).sum(),
+            vec.iter().filter(|line| !line.is_empty()).count(),
+        ),
+        _ => (0, 0),
+    };
+
+    let size = prefix
+        .len()
+        .checked_mul(count)
+        .and_then(|len| len.checked_mul(lines))
+        .and_then(|len| len.checked_add(size))
+        .unwrap_or(usize::MAX);
+    if size > max_size {
+        return Err(Error::TooLarge(size, max_size));
+    }
+
+    __layout(input, |lines| {
+        lines
+            .iter()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    let mut ret = String::with_capacity(prefix.len() * count + line.len());
+                    ret.extend(std::iter::repeat(prefix).take(count));
+                    ret.push_str(line);
+                    ret
+                }
+            })
+            .collect()
+    })
+}
+
+///
+/// Removes the longest common leading whitespace from every line of the given input, lines
+/// containing only whitespace are ignored and emptied.
+///
+/// ### Accepts
+///
+/// * [`Kind::String`], lines are separated by `\n`
+/// * [`Kind::StringVec`], every element is a line
+///
+/// ### Returns
+///
+/// * [`Ok(stringOrVec)`]
+///   * `stringOrVec` contains the dedented lines
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn dedent(input: Value) -> Result<Value, Error> {
+    __layout(input, |lines| {
+        let common = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| __leading(line))
+            .reduce(__common_prefix)
+            .unwrap_or_default();
+
+        lines
+            .iter()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    String::new()
+                } else {
+                    line[common.len()..].to_owned()
+                }
+            })
+            .collect()
+    })
+}
+
+///
+/// Renders the given input as a plain text table with aligned columns. The leading `<`, `>` and
+/// `=` [`char`]s of `spec` are the left, right and center alignments of the columns in order,
+/// the rest is the column separator. Columns without alignment are left aligned, the separator
+/// defaults to two spaces.
+///
+/// ### Accepts
+///
+/// * [`Kind::StringVec`], every element is a row of a single column
+/// * [`Kind::StringVecVec`], every element is a row
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the table, one row per line
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn table(input: Value, spec: &str) -> Result<Value, Error> {
+    let rows = __rows(input)?;
+
+    let split = spec
+        .find(|ch| !matches!(ch, '<' | '>' | '='))
+        .unwrap_or(spec.len());
+    let (aligns, separator) = spec.split_at(split);
+    let separator = if separator.is_empty() {
+        "  "
+    } else {
+        separator
+    };
+
+    let aligns = aligns.chars().collect::<Vec<_>>();
+    let widths = __widths(&rows);
+
+    Ok(Value::String(
+        rows.iter()
+            .map(|row| {
+                widths
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &width)| {
+                        let cell = row.get(idx).map(String::as_str).unwrap_or_default();
+                        __align(cell, width, aligns.get(idx).copied().unwrap_or('<'))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator)
+                    .trim_end()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ))
+}
+
+///
+/// Renders the given input as a markdown table, the first row is the header. The `<`, `>` and
+/// `=` [`char`]s of `aligns` are the left, right and center alignments of the columns in order,
+/// columns without alignment use the default alignment.
+///
+/// ### Accepts
+///
+/// * [`Kind::StringVec`], every element is a row of a single column
+/// * [`Kind::StringVecVec`], every element is a row
+///
+/// ### Returns
+///
+/// * [`Ok(Value::String(string))`]
+///   * `string` contains the table, one row per line
+/// * [`Err(error)`]
+///   * `error` contains an arg type mismatch [`Error`]
+///
+pub fn markdown(input: Value, aligns: &str) -> Result<Value, Error> {
+    let rows = __rows(input)?
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|cell| cell.replace('|', "\\|"))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let aligns = aligns.chars().collect::<Vec<_>>();
+    let widths = __widths(&rows)
+        .into_iter()
+        .map(|width| width.max(3))
+        .collect::<Vec<_>>();
+
+    let render = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let render_row = |row: &Vec<String>| {
+        render(
+            widths
+                .iter()
+                .enumerate()
+                .map(|(idx, &width)| {
+                    let cell = row.get(idx).map(String::as_str).unwrap_or_default();
+                    __align(cell, width, aligns.get(idx).copied().unwrap_or('<'))
+                })
+                .collect(),
+        )
+    };
+
+    let rule = render(
+        widths
+            .iter()
+            .enumerate()
+            .map(|(idx, &width)| match aligns.get(idx) {
+                Some('<') => format!(":{}", "-".repeat(width - 1)),
+                Some('>') => format!("{}:", "-".repeat(width - 1)),
+                Some('=') => format!(":{}:", "-".repeat(width - 2)),
+                _ => "-".repeat(width),
+            })
+            .collect(),
+    );
+
+    let mut lines = rows.iter().map(render_row).collect::<Vec<_>>();
+    if !lines.is_empty() {
+        lines.insert(1, rule);
+    }
+
+    Ok(Value::String(lines.join("\n")))
+}
+
+fn __rows(input: Value) -> Result<Vec<Vec<String>>, Error> {
+    match input {
+        Value::StringVec(vec) => Ok(vec.into_iter().map(|string| vec![string]).collect()),
+        Value::StringVecVec(vec) => Ok(vec),
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::StringVec, Kind::StringVecVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __widths(rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths = vec![];
+    for row in rows {
+        for (idx, cell) in row.iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(idx) {
+                Some(max) if *max < width => *max = width,
+                Some(_) => {}
+                None => widths.push(width),
+            }
+        }
+    }
+
+    widths
+}
+
+fn __align(cell: &str, width: usize, align: char) -> String {
+    let missing = width.saturating_sub(cell.chars().count());
+
+    // the left side gets the smaller half if centered
+    let left = match align {
+        '>' => missing,
+        '=' => missing / 2,
+        _ => 0,
+    };
+
+    format!("{}{}{}", " ".repeat(left), cell, " ".repeat(missing - left))
+}
+
+fn __layout(input: Value, layout: impl FnOnce(&[&str]) -> Vec<String>) -> Result<Value, Error> {
+    match input {
+        Value::String(string) => {
+            let lines = string.split('\n').collect::<Vec<_>>();
+            Ok(Value::String(layout(&lines).join("\n")))
+        }
+        Value::StringVec(vec) => {
+            let lines = vec.iter().map(String::as_str).collect::<Vec<_>>();
+            Ok(Value::StringVec(layout(&lines)))
+        }
+        x => Err(Error::ArgMismatch(ArgMismatchError::new(
+            &[Kind::String, Kind::StringVec],
+            x.kind(),
+        ))),
+    }
+}
+
+fn __wrap(line: &str, width: usize) -> Vec<String> {
+    let indent = __leading(line);
+    let indent_len = indent.chars().count();
+
+    let mut rows = vec![];
+    let mut row = indent.to_owned();
+    let mut row_len = indent_len;
+    let mut has_word = false;
+
+    for word in line.split_whitespace() {
+        let word_len = word.chars().count();
+
+        if has_word {
+            if row_len + 1 + word_len > width {
+                rows.push(mem::replace(&mut row, indent.to_owned()));
+                row_len = indent_len;
+            } else {
+                row.push(' ');
+                row_len += 1;
+            }
+        }
+
+        row.push_str(word);
+        row_len += word_len;
+        has_word = true;
+    }
+
+    // blank lines are kept as they are
+    rows.push(row);
+    rows
+}
+
+fn __leading(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn __common_prefix<'a>(first: &'a str, second: &str) -> &'a str {
+    let len = first
+        .char_indices()
+        .zip(second.chars())
+        .take_while(|((_, left), right)| left == right)
+        .last()
+        .map_or(0, |((idx, ch), _)| idx + ch.len_utf8());
+
+    &first[..len]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wrap() {
+        assert_eq!(
+            super::wrap(
+                Value::String("the quick  brown fox\n\n  jumps over the lazy dog".to_owned()),
+                10
+            )
+            .unwrap(),
+            Value::String("the quick\nbrown fox\n\n  jumps\n  over the\n  lazy dog".to_owned())
+        );
+        assert_eq!(
+            super::wrap(
+                Value::StringVec(vec!["a verylongword b".to_owned(), "c".to_owned()]),
+                4
+            )
+            .unwrap(),
+            Value::StringVec(vec![
+                "a".to_owned(),
+                "verylongword".to_owned(),
+                "b".to_owned(),
+                "c".to_owned()
+            ])
+        );
+        assert!(super::wrap(Value::Bool(true), 4).is_err());
+    }
+
+    #[test]
+    fn table() {
+        let rows = Value::StringVecVec(vec![
+            vec!["name".to_owned(), "size".to_owned(), "ok".to_owned()],
+            vec!["a".to_owned(), "12".to_owned()],
+            vec!["bcdef".to_owned(), "3".to_owned(), "yes".to_owned()],
+        ]);
+
+        assert_eq!(
+            super::table(rows.clone(), "").unwrap(),
+            Value::String("name   size  ok\na      12\nbcdef  3     yes".to_owned())
+        );
+        assert_eq!(
+            super::table(rows, "<>= | ").unwrap(),
+            Value::String("name  | size | ok\na     |   12 |\nbcdef |    3 | yes".to_owned())
+        );
+        assert_eq!(
+            super::table(Value::StringVec(vec!["a".to_owned(), "bc".to_owned()]), ">").unwrap(),
+            Value::String(" a\nbc".to_owned())
+        );
+        assert!(super::table(Value::String("a".to_owned()), "").is_err());
+    }
+
+    #[test]
+    fn markdown() {
+        let rows = Value::StringVecVec(vec![
+            vec!["name".to_owned(), "size".to_owned()],
+            vec!["a|b".to_owned(), "12".to_owned()],
+        ]);
+
+        assert_eq!(
+            super::markdown(rows.clone(), "").unwrap(),
+            Value::String("| name | size |\n| ---- | ---- |\n| a\\|b | 12   |".to_owned())
+        );
+        assert_eq!(
+            super::markdown(rows, "=>").unwrap(),
+            Value::String("| name | size |\n| :--: | ---: |\n| a\\|b |   12 |".to_owned())
+        );
+        assert_eq!(
+            super::markdown(Value::StringVecVec(vec![]), "").unwrap(),
+            Value::String("".to_owned())
+        );
+    }
+
+    #[test]
+    fn indent() {
+        assert_eq!(
+            super::indent(Value::String("a\n\n b\n".to_owned()), "> ", 1, 1024).unwrap(),
+            Value::String("> a\n\n>  b\n".to_owned())
+        );
+        assert_eq!(
+            super::indent(
+                Value::StringVec(vec!["a".to_owned(), "".to_owned()]),
+                " ",
+                4,
+                1024
+            )
+            .unwrap(),
+            Value::StringVec(vec!["    a".to_owned(), "".to_owned()])
+        );
+        assert!(super::indent(Value::String("a\nb".to_owned()), " ", 510, 1024).is_ok());
+        assert!(matches!(
+            super::indent(Value::String("a\n\nb".to_owned()), " ", 512, 1024),
+            Err(Error::TooLarge(1028, 1024))
+        ));
+        assert!(matches!(
+            super::indent(Value::String("a".to_owned()), " ", usize::MAX, 1024),
+            Err(Error::TooLarge(usize::MAX, 1024))
+        ));
+        assert_eq!(
+            super::indent(Value::String("\n".to_owned()), " ", usize::MAX, 1024).unwrap(),
+            Value::String("\n".to_owned())
+        );
+        assert!(super::indent(Value::Bool(true), " ", 1, 1024).is_err());
+    }
+
+    #[test]
+    fn dedent() {
+        assert_eq!(
+            super::dedent(Value::String("    a\n      b\n  \n    c".to_owned())).unwrap(),
+            Value::String("a\n  b\n\nc".to_owned())
+        );
+        assert_eq!(
+            super::dedent(Value::StringVec(vec![
+                "\t a".to_owned(),
+                "\t\tb".to_owned()
+            ]))
+            .unwrap(),
+            Value::StringVec(vec![" a".to_owned(), "\tb".to_owned()])
+        );
+        assert_eq!(
+            super::dedent(Value::String("a\n b".to_owned())).unwrap(),
+            Value::String("a\n b".to_owned())
+        );
+        assert!(super::dedent(Value::Bool(true)).is_err());
+    }
+}
@@ -4,8 +4,11 @@ pub use self::err::Error;
 
 mod func;
 
+mod layout;
+
 mod context;
 pub use context::Context;
+pub use context::DEFAULT_MAX_SIZE;
 
 mod value;
 pub use value::Kind;
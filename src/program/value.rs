@@ -15,6 +15,11 @@ pub enum Kind {
     ///
     StringVec,
 
+    ///
+    /// A vec of vecs of [`String`]s.
+    ///
+    StringVecVec,
+
     ///
     /// A [`bool`].
     ///
@@ -26,6 +31,7 @@ impl Display for Kind {
         match self {
             Self::String => f.write_str("String"),
             Self::StringVec => f.write_str("StringVec"),
+            Self::StringVecVec => f.write_str("StringVecVec"),
             Self::Bool => f.write_str("Bool"),
         }
     }
@@ -46,6 +52,11 @@ pub enum Value {
     ///
     StringVec(Vec<String>),
 
+    ///
+    /// A vec of vecs of [`String`]s.
+    ///
+    StringVecVec(Vec<Vec<String>>),
+
     ///
     /// A [`bool`].
     ///
@@ -60,6 +71,7 @@ impl Value {
         match self {
             Self::String(_) => Kind::String,
             Self::StringVec(_) => Kind::StringVec,
+            Self::StringVecVec(_) => Kind::StringVecVec,
             Self::Bool(_) => Kind::Bool,
         }
     }
@@ -71,6 +83,7 @@ impl Debug for Value {
             // use debug version to show explicit delimiters
             Self::String(inner) => Debug::fmt(inner, f),
             Self::StringVec(inner) => Debug::fmt(inner, f),
+            Self::StringVecVec(inner) => Debug::fmt(inner, f),
             Self::Bool(inner) => Debug::fmt(inner, f),
         }
     }
@@ -93,6 +106,25 @@ impl Display for Value {
 
                 Ok(())
             }
+            Self::StringVecVec(inner) => {
+                // one row per line, elements separated by spaces
+                for (idx, row) in inner.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_char('\n')?;
+                    }
+
+                    let mut iter = row.iter();
+                    if let Some(first) = iter.next() {
+                        write!(f, "{}", first)?;
+                        for string in iter {
+                            f.write_char(' ')?;
+                            write!(f, "{}", string)?;
+                        }
+                    }
+                }
+
+                Ok(())
+            }
             Self::Bool(inner) => Display::fmt(inner, f),
         }
     }
@@ -2,6 +2,7 @@ use color_eyre::Report;
 
 use crate::{
     cli,
+    helper::Rng,
     parse::EagerGen,
     program::{Context as ProgramContext, Value},
 };
@@ -17,15 +18,20 @@ use std::{
 ///
 pub struct Context {
     values: VecDeque<Value>,
+    max_size: usize,
+    rng: Rng,
 }
 
 impl Context {
     ///
-    /// Creates a new [`Context`] with the given [`Io`] instances.
+    /// Creates a new [`Context`] with the given maximum value size in bytes for all programs and
+    /// the [`Rng`] seeding the [`Rng`] of each program.
     ///
-    pub fn new() -> Self {
+    pub fn new(max_size: usize, rng: Rng) -> Self {
         Self {
             values: VecDeque::new(),
+            max_size,
+            rng,
         }
     }
 
@@ -78,7 +84,15 @@ impl Context {
                 }
             };
 
-            let mut program = ProgramContext::new(&input, gen, None, &mut self.values, true);
+            let mut program = ProgramContext::new(
+                &input,
+                gen,
+                None,
+                &mut self.values,
+                true,
+                self.max_size,
+                Rng::new(self.rng.next_u64()),
+            );
 
             'inner: loop {
                 match Pin::new(&mut program).resume(()) {
@@ -116,6 +130,7 @@ impl Context {
                     "  h |  help    print all commands",
                     "  q | queue    display the current queue",
                     "  c | clear    clear the current queue",
+                    "     seed x    reseed the random generator with x",
                     "v | v+ | v-    view/increase/decrease logging verbosity",
                     "     tokens    display all token descriptions",
                     "    example    display examples",
@@ -167,26 +182,103 @@ impl Context {
                     "Token    take:push description",
                     "Basic Tokens:",
                     "    <    0:1       input",
+                    "  <:s    0:1       all remaining input as one string",
+                    "  <:l    0:1       all remaining input as array of lines",
                     "    >    1:0       output",
-                    "    ~    2:1       concat",
+                    "    ~    2:1       concat strings, arrays or rows",
                     "    *    1:2       duplicate",
                     "   @x    x:x       rotate queue x times",
+                    ",|x.y|   0:1       numbers from x to exclusive y, optional step ,|x.y.s|",
+                    ",|x.y|   0:1       chars from literal x to inclusive literal y, like ,|\"a\".\"z\"|",
+                    "   ,a    0:1       lowercase alphabet, ,A uppercase, ,d digits",
+                    "    ?    1:1       pick a random element or char",
+                    "   ?x    1:1       pick x random elements or chars",
+                    "   ??    1:1       shuffle elements or chars",
+                    "   xn    1:1       repeat n times, pops n after the value if not given",
                     "Checks:",
                     "    v    1:1       check all ascii vowel",
                     "    c    1:1       check all ascii consonant",
                     "    _    1:1       check all ascii lower",
                     "    ^    1:1       check all ascii upper",
+                    " g\"x\"    1:1       check all match glob x: * ? [abc] [!a-z] **",
                     "Transforms:",
-                    "    .    1:1       join array elements to string",
-                    "  /x/    1:1       splits string by literal or into chars if x not given",
+                    "    .    1:1       join array elements to string or rows to array",
+                    "  /x/    1:1       splits string by literal or into chars if x not given, array into rows",
+                    "   wx    1:1       wrap lines at width x on word boundaries",
+                    "   ix    1:1       indent lines by x spaces or by literal x",
+                    "    d    1:1       remove common leading whitespace of lines",
+                    "   tx    1:1       table of array or rows, optional literal x alignments <>= and separator",
+                    "   Tx    1:1       markdown table of array or rows, optional literal x alignments <>=",
                     "|x.y|    1:1       slices by abs or rel indecies",
                     "  |x|    1:1       indexes by abs or rel index",
+                    " +|x|    2:1       inserts second value into first before index x",
+                    " -|x|    1:1       removes at index x, -|x.y| removes slice",
+                    " =|x|    2:1       replaces index x of first by second, =|x.y| replaces slice",
+                    "|\"x\"|    1:1       finds first index of literal x or false",
+                    "|-\"x\"|   1:1       finds last index of literal x or false",
+                    "==\"x\"    1:1       check equal to literal x, all elements",
+                    "=?\"x\"    1:1       check contains literal x or any element equal",
+                    "   %%    1:1       remove duplicate chars or elements, %%I ignoring case",
+                    " I\"x\"    ---       ignore case of literal x in split, count, find and checks",
+                    " #[x]    1:1       count all passing x: Checks",
+                    " #\"x\"    1:1       count occurences of literal x",
+                    "    #    1:1       frequency table of all, most frequent first",
+                    "   =l    2:1       levenshtein distance",
+                    "   =d    2:1       damerau-levenshtein distance",
+                    "   =j    2:1       jaro-winkler similarity",
+                    "   =u    2:1       unified diff of lines",
+                    "$\"{}\"    n:1       template with placeholders {}, {0} or {0:sep}",
+                    "   'x    1:1       char codes of string in radix x: d, x or b",
+                    "  '!x    1:1       string from char codes in radix x: d, x or b",
+                    "   's    1:1       to string, joined by spaces and newlines",
+                    "   'l    1:1       to array, wrapping or flattening",
+                    "   '?    1:1       to bool, false if empty, \"0\" or \"false\"",
+                    "   'n    1:1       parse numbers",
+                    "   'k    1:1       name of the value's kind",
                     "  [x]    1:1       filter all by x: Checks",
+                    "[g\"x\"]   1:1       filter array elements by glob x",
                     "  {x}    1:1       map all by x: Maps",
+                    " %[x]    1:2       partition all by x: Checks, passing first",
+                    "   %=    1:1       group consecutive equal chars or elements",
+                    "   ;x    1:1       select first min <, max >, shortest - or longest + element",
+                    "  ;#x    1:1       index of first min <, max >, shortest - or longest + element",
+                    "[(x)]    1:1       filter all by sub-program x pushing a bool",
+                    "{(x)}    1:1       map all by sub-program x",
+                    " &(x)    2:1       fold all into initial value by sub-program x",
                     "Maps:",
                     "    _    ---       to ascii lower case",
                     "    ^    ---       to ascii upper case",
                     "    %    ---       to swaped ascii case",
+                    "    t    ---       to title case",
+                    "    f    ---       to first char upper case",
+                    "    s    ---       to snake_case",
+                    "    S    ---       to SCREAMING_CASE",
+                    "    k    ---       to kebab-case",
+                    "    c    ---       to camelCase",
+                    "    p    ---       to PascalCase",
+                    "  nfc    ---       to unicode nfc, likewise nfd, nfkc and nfkd",
+                    "nodia    ---       remove diacritics",
+                    "ascii    ---       transliterate to ascii",
+                    "   -x    ---       trim whitespace or literal x chars, -< start, -> end",
+                    "   <x    ---       pad left to width x, optional literal fill char",
+                    "   >x    ---       pad right to width x, optional literal fill char",
+                    "   =x    ---       pad center to width x, optional literal fill char",
+                    "   .x    ---       truncate to width x with ellipsis",
+                    "  r13    ---       rotate ascii letters by 13",
+                    "  r:x    ---       rotate ascii letters by x, may be negative",
+                    "atbash   ---       mirror ascii letters in the alphabet",
+                    "  ord    ---       to decimal char code, ord:x hex, ord:b binary",
+                    "  chr    ---       from decimal char code, chr:x hex, chr:b binary",
+                    "  b64    ---       to base64, !b64 from base64",
+                    " b64u    ---       to url safe base64, !b64u from url safe base64",
+                    "  hex    ---       to hex, !hex from hex",
+                    "  url    ---       to url percent encoding, !url from percent encoding",
+                    " json    ---       to json string literal, !json from json string literal",
+                    "  rle    ---       run-length encode, !rle decode, digits and \\ escaped by \\",
+                    "   sh    ---       to single quoted shell word, !sh from shell word",
+                    " html    ---       to escaped html, !html from escaped html",
+                    "  csv    ---       to csv field, !csv from csv field",
+                    "   re    ---       to escaped regex, !re from escaped regex",
                 ];
 
                 for token in tokens {
@@ -199,7 +291,7 @@ impl Context {
                 let examples = [
                     "        <>    echo program",
                     "      <*>>    duplicate echo",
-                    "<*[^][_]~>    orders by case, upper first",
+                    "   <%[^]~>    orders by case, upper first",
                     "   <|.-3|>    relative slicing like [..len - 3]",
                 ];
 
@@ -210,6 +302,12 @@ impl Context {
                 Ok(false)
             }
             "exit" => Ok(true),
+            _ if cmd.starts_with("seed ") => {
+                let seed = cmd["seed ".len()..].trim().parse()?;
+                self.rng.reseed(seed);
+                log::info!("reseeded with [{}]", seed);
+                Ok(false)
+            }
             _ => color_eyre::eyre::bail!(format!("unknown command: '{}'", cmd)),
         }
     }